@@ -46,6 +46,17 @@ function printDefaultConfigWarning() {
   );
 }
 
+function parseMethodList(methods?: string): string[] | undefined {
+  if (methods === undefined) {
+    return undefined;
+  }
+
+  return methods
+    .split(",")
+    .map((method) => method.trim())
+    .filter((method) => method !== "");
+}
+
 function logHardhatNetworkAccounts(networkConfig: HardhatNetworkConfig) {
   const isDefaultConfig =
     !Array.isArray(networkConfig.accounts) &&
@@ -173,20 +184,40 @@ subtask(TASK_NODE_CREATE_SERVER)
   .addParam("hostname", undefined, undefined, types.string)
   .addParam("port", undefined, undefined, types.int)
   .addParam("provider", undefined, undefined, types.any)
+  .addOptionalParam("readOnly", undefined, false, types.boolean)
+  .addOptionalParam("allowFaucet", undefined, false, types.boolean)
+  .addOptionalParam("allowedMethods", undefined, undefined, types.any)
+  .addOptionalParam("disabledMethods", undefined, undefined, types.any)
+  .addOptionalParam("logCorrelationIds", undefined, false, types.boolean)
   .setAction(
     async ({
       hostname,
       port,
       provider,
+      readOnly,
+      allowFaucet,
+      allowedMethods,
+      disabledMethods,
+      logCorrelationIds,
     }: {
       hostname: string;
       port: number;
       provider: EthereumProvider;
+      readOnly: boolean;
+      allowFaucet: boolean;
+      allowedMethods?: string[];
+      disabledMethods?: string[];
+      logCorrelationIds: boolean;
     }): Promise<JsonRpcServer> => {
       const serverConfig: JsonRpcServerConfig = {
         hostname,
         port,
         provider,
+        readOnly,
+        allowFaucet,
+        allowedMethods,
+        disabledMethods,
+        logCorrelationIds,
       };
 
       const server = new JsonRpcServerImpl(serverConfig);
@@ -274,6 +305,36 @@ task(TASK_NODE, "Starts a JSON-RPC server on top of Hardhat Network")
     undefined,
     types.int
   )
+  .addOptionalParam(
+    "readOnlyPort",
+    "The port of an additional read-only server, which rejects the methods that modify the chain",
+    undefined,
+    types.int
+  )
+  .addOptionalParam(
+    "allowedMethods",
+    "A comma-separated list of the only methods the server accepts. Namespaces can be given as patterns like hardhat_*",
+    undefined,
+    types.string
+  )
+  .addOptionalParam(
+    "disabledMethods",
+    "A comma-separated list of methods the server rejects. Namespaces can be given as patterns like evm_*",
+    undefined,
+    types.string
+  )
+  .addOptionalParam(
+    "readOnlyAllowedMethods",
+    "Like --allowed-methods, but for the read-only server. Defaults to the list of --allowed-methods",
+    undefined,
+    types.string
+  )
+  .addOptionalParam(
+    "readOnlyDisabledMethods",
+    "Like --disabled-methods, but for the read-only server. Defaults to the list of --disabled-methods",
+    undefined,
+    types.string
+  )
   .setAction(
     async (
       {
@@ -281,13 +342,23 @@ task(TASK_NODE, "Starts a JSON-RPC server on top of Hardhat Network")
         fork: forkUrl,
         hostname: hostnameParam,
         port,
+        readOnlyPort,
+        allowedMethods: allowedMethodsParam,
+        disabledMethods: disabledMethodsParam,
+        readOnlyAllowedMethods: readOnlyAllowedMethodsParam,
+        readOnlyDisabledMethods: readOnlyDisabledMethodsParam,
       }: {
         forkBlockNumber?: number;
         fork?: string;
         hostname?: string;
         port: number;
+        readOnlyPort?: number;
+        allowedMethods?: string;
+        disabledMethods?: string;
+        readOnlyAllowedMethods?: string;
+        readOnlyDisabledMethods?: string;
       },
-      { config, hardhatArguments, network, run }
+      { config, hardhatArguments, network, run, userConfig }
     ) => {
       // we throw if the user specified a network argument and it's not hardhat
       if (
@@ -319,10 +390,21 @@ task(TASK_NODE, "Starts a JSON-RPC server on top of Hardhat Network")
           }
         }
 
+        const allowedMethods = parseMethodList(allowedMethodsParam);
+        const disabledMethods = parseMethodList(disabledMethodsParam);
+
+        // The correlation ids are printed along with the node's logs, which
+        // are enabled by default
+        const logCorrelationIds =
+          userConfig.networks?.[HARDHAT_NETWORK_NAME]?.loggingEnabled ?? true;
+
         const server: JsonRpcServer = await run(TASK_NODE_CREATE_SERVER, {
           hostname,
           port,
           provider,
+          allowedMethods,
+          disabledMethods,
+          logCorrelationIds,
         });
 
         await run(TASK_NODE_SERVER_CREATED, {
@@ -334,6 +416,38 @@ task(TASK_NODE, "Starts a JSON-RPC server on top of Hardhat Network")
 
         const { port: actualPort, address } = await server.listen();
 
+        let readOnlyServer: JsonRpcServer | undefined;
+        if (readOnlyPort !== undefined) {
+          const createdReadOnlyServer: JsonRpcServer = await run(
+            TASK_NODE_CREATE_SERVER,
+            {
+              hostname,
+              port: readOnlyPort,
+              provider,
+              readOnly: true,
+              // The faucet creates ETH, so it's only public if it's limited
+              allowFaucet:
+                config.networks[HARDHAT_NETWORK_NAME].faucetRateLimit !==
+                undefined,
+              allowedMethods:
+                parseMethodList(readOnlyAllowedMethodsParam) ?? allowedMethods,
+              disabledMethods:
+                parseMethodList(readOnlyDisabledMethodsParam) ??
+                disabledMethods,
+              logCorrelationIds,
+            }
+          );
+
+          const readOnlyAddress = await createdReadOnlyServer.listen();
+          readOnlyServer = createdReadOnlyServer;
+
+          console.log(
+            chalk.green(
+              `Started read-only HTTP and WebSocket JSON-RPC server at http://${readOnlyAddress.address}:${readOnlyAddress.port}/`
+            )
+          );
+        }
+
         let watcher: Watcher | undefined;
         try {
           watcher = await watchCompilerOutput(provider, config.paths);
@@ -362,6 +476,7 @@ task(TASK_NODE, "Starts a JSON-RPC server on top of Hardhat Network")
         });
 
         await server.waitUntilClosed();
+        await readOnlyServer?.close();
         await watcher?.close();
       } catch (error) {
         if (HardhatError.isHardhatError(error)) {
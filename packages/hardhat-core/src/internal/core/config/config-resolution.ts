@@ -168,6 +168,11 @@ function resolveHardhatNetworkConfig(
     if (httpHeaders !== undefined) {
       forking.httpHeaders = httpHeaders;
     }
+
+    const prefetch = hardhatNetworkConfig.forking?.prefetch;
+    if (prefetch !== undefined) {
+      forking.prefetch = prefetch;
+    }
   }
 
   const mining = resolveMiningConfig(hardhatNetworkConfig.mining);
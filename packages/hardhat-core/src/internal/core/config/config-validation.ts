@@ -218,10 +218,19 @@ const Integer = new t.Type<number>(
   t.identity
 );
 
+// Each slot of a prefetched storage range is a request to the remote node
+const MAX_PREFETCHED_STORAGE_RANGE_SIZE = 10_000;
+
+const HardhatNetworkForkingPrefetchConfig = t.type({
+  address,
+  storageRanges: optional(t.array(t.type({ from: t.number, to: t.number }))),
+});
+
 const HardhatNetworkForkingConfig = t.type({
   enabled: optional(t.boolean),
   url: t.string,
   blockNumber: optional(t.number),
+  prefetch: optional(t.array(HardhatNetworkForkingPrefetchConfig)),
 });
 
 const HardhatNetworkMempoolConfig = t.type({
@@ -299,6 +308,20 @@ const HardhatNetworkConfig = t.type({
   mining: optional(HardhatNetworkMiningConfig),
   coinbase: optional(address),
   chains: optional(HardhatNetworkChainsConfig),
+  checksumAddresses: optional(t.boolean),
+  faucetRateLimit: optional(
+    t.type({
+      maxAmount: decimalString,
+      interval: t.number,
+    })
+  ),
+  gasPriceOracle: optional(
+    t.type({
+      blocks: t.number,
+      percentile: t.number,
+      fallbackMaxPriorityFeePerGas: optional(decimalString),
+    })
+  ),
 });
 
 const HDAccountsConfig = t.type({
@@ -517,6 +540,64 @@ export function getValidationErrors(config: any): string[] {
           );
         }
       }
+
+      const gasPriceOracle = hardhatNetwork.gasPriceOracle;
+      if (typeof gasPriceOracle === "object" && gasPriceOracle !== null) {
+        const { blocks, percentile } = gasPriceOracle;
+
+        if (
+          typeof blocks === "number" &&
+          (!Number.isInteger(blocks) || blocks < 1 || blocks > 1024)
+        ) {
+          errors.push(
+            `HardhatConfig.networks.${HARDHAT_NETWORK_NAME}.gasPriceOracle.blocks must be an integer between 1 and 1024, but got ${blocks}`
+          );
+        }
+
+        if (
+          typeof percentile === "number" &&
+          (percentile < 0 || percentile > 100)
+        ) {
+          errors.push(
+            `HardhatConfig.networks.${HARDHAT_NETWORK_NAME}.gasPriceOracle.percentile must be between 0 and 100, but got ${percentile}`
+          );
+        }
+      }
+
+      const interval = hardhatNetwork.faucetRateLimit?.interval;
+      if (typeof interval === "number" && !(interval > 0)) {
+        errors.push(
+          `HardhatConfig.networks.${HARDHAT_NETWORK_NAME}.faucetRateLimit.interval must be greater than 0, but got ${interval}`
+        );
+      }
+
+      const prefetch = hardhatNetwork.forking?.prefetch;
+      if (Array.isArray(prefetch)) {
+        prefetch.forEach((account: any, accountIndex: number) => {
+          const storageRanges: unknown = account?.storageRanges;
+          if (!Array.isArray(storageRanges)) {
+            return;
+          }
+
+          storageRanges.forEach(({ from, to }: any, rangeIndex: number) => {
+            if (typeof from !== "number" || typeof to !== "number") {
+              return;
+            }
+
+            if (
+              !Number.isInteger(from) ||
+              !Number.isInteger(to) ||
+              from < 0 ||
+              from > to ||
+              to - from >= MAX_PREFETCHED_STORAGE_RANGE_SIZE
+            ) {
+              errors.push(
+                `HardhatConfig.networks.${HARDHAT_NETWORK_NAME}.forking.prefetch[${accountIndex}].storageRanges[${rangeIndex}] must have non-negative integers as "from" and "to", with "from" not greater than "to", and at most ${MAX_PREFETCHED_STORAGE_RANGE_SIZE} slots, but got { from: ${from}, to: ${to} }`
+              );
+            }
+          });
+        });
+      }
     }
 
     for (const [networkName, netConfig] of Object.entries<any>(
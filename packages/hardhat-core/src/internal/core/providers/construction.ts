@@ -17,6 +17,7 @@ import type {
   MempoolOrder,
 } from "../../hardhat-network/provider/node-types";
 import type * as DiskCacheT from "../../hardhat-network/provider/utils/disk-cache";
+import type { PercentileGasPriceOracleConfig } from "../../hardhat-network/provider/utils/gasPriceOracle";
 import { HARDHAT_NETWORK_NAME } from "../../constants";
 import { parseDateString } from "../../util/date";
 
@@ -74,6 +75,7 @@ export async function createProvider(
         jsonRpcUrl: hardhatNetConfig.forking?.url,
         blockNumber: hardhatNetConfig.forking?.blockNumber,
         httpHeaders: hardhatNetConfig.forking.httpHeaders,
+        prefetch: hardhatNetConfig.forking.prefetch,
       };
     }
 
@@ -81,6 +83,22 @@ export async function createProvider(
       hardhatNetConfig.accounts
     );
 
+    let gasPriceOracle: PercentileGasPriceOracleConfig | undefined;
+
+    if (hardhatNetConfig.gasPriceOracle !== undefined) {
+      const { blocks, percentile, fallbackMaxPriorityFeePerGas } =
+        hardhatNetConfig.gasPriceOracle;
+
+      gasPriceOracle = {
+        blocks,
+        percentile,
+        fallbackMaxPriorityFeePerGas:
+          fallbackMaxPriorityFeePerGas !== undefined
+            ? BigInt(fallbackMaxPriorityFeePerGas)
+            : undefined,
+      };
+    }
+
     const { getForkCacheDirPath } =
       require("../../hardhat-network/provider/utils/disk-cache") as typeof DiskCacheT;
 
@@ -114,6 +132,18 @@ export async function createProvider(
           paths !== undefined ? getForkCacheDirPath(paths) : undefined,
         enableTransientStorage:
           hardhatNetConfig.enableTransientStorage ?? false,
+        checksumAddresses: hardhatNetConfig.checksumAddresses ?? false,
+        faucetRateLimit:
+          hardhatNetConfig.faucetRateLimit !== undefined
+            ? {
+                maxAmount: BigInt(hardhatNetConfig.faucetRateLimit.maxAmount),
+                interval: hardhatNetConfig.faucetRateLimit.interval,
+              }
+            : undefined,
+        gasPriceOracle,
+        hdAccounts: Array.isArray(hardhatNetConfig.accounts)
+          ? undefined
+          : hardhatNetConfig.accounts,
       },
       {
         enabled: hardhatNetConfig.loggingEnabled,
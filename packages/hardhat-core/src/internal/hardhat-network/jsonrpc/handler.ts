@@ -1,3 +1,4 @@
+import debug from "debug";
 import { IncomingMessage, ServerResponse } from "http";
 import getRawBody from "raw-body";
 import WebSocket from "ws";
@@ -5,6 +6,7 @@ import WebSocket from "ws";
 import { EIP1193Provider } from "../../../types";
 import {
   InternalError,
+  InvalidArgumentsError,
   InvalidJsonInputError,
   InvalidRequestError,
   ProviderError,
@@ -17,11 +19,63 @@ import {
   JsonRpcRequest,
   JsonRpcResponse,
 } from "../../util/jsonrpc";
+import { extractTraceExportOption } from "../provider/utils/traceExport";
+
+import { MethodFilter } from "./method-filter";
+import { ConnectionSession, SET_SESSION_DEFAULTS_METHOD } from "./session";
 
 /* eslint-disable @nomicfoundation/hardhat-internal-rules/only-hardhat-error */
 
+const log = debug("hardhat:core:hardhat-network:jsonrpc");
+
+// HTTP header that clients can use to correlate their requests with the
+// node's logs. For WebSocket connections, the header of the upgrade request
+// applies to every request of the connection. Each JSON-RPC request can also
+// have a `correlationId` field, which takes precedence over the header.
+export const CORRELATION_ID_HEADER = "x-correlation-id";
+
+/**
+ * Returns the correlation id of a request, if it has one.
+ */
+export function getCorrelationId(
+  req: JsonRpcRequest,
+  header?: string | string[]
+): string | undefined {
+  const { correlationId } = req as JsonRpcRequest & {
+    correlationId?: unknown;
+  };
+  if (typeof correlationId === "string") {
+    return correlationId;
+  }
+
+  return Array.isArray(header) ? header[0] : header;
+}
+
+/**
+ * Throws if a request uses an option that only local callers of the provider
+ * can use, like `exportToFile`, which writes traces to the node's disk.
+ */
+export function assertServerRequestOptions(req: JsonRpcRequest) {
+  const [, exportToFile] = extractTraceExportOption(
+    req.method,
+    req.params ?? []
+  );
+
+  if (exportToFile) {
+    throw new InvalidArgumentsError(
+      "The exportToFile option isn't available through the JSON-RPC server"
+    );
+  }
+}
+
 export class JsonRpcHandler {
-  constructor(private readonly _provider: EIP1193Provider) {}
+  constructor(
+    private readonly _provider: EIP1193Provider,
+    private readonly _methodFilter: MethodFilter = new MethodFilter(),
+    // If present, it prints the correlation id of each request that has one,
+    // along with the logs of the node
+    private readonly _printLine?: (line: string) => void
+  ) {}
 
   public handleHttp = async (req: IncomingMessage, res: ServerResponse) => {
     this._setCorsHeaders(res);
@@ -30,6 +84,11 @@ export class JsonRpcHandler {
       return;
     }
 
+    const correlationHeader = req.headers[CORRELATION_ID_HEADER];
+    if (correlationHeader !== undefined) {
+      res.setHeader(CORRELATION_ID_HEADER, correlationHeader);
+    }
+
     let jsonHttpRequest: any;
     try {
       jsonHttpRequest = await _readJsonHttpRequest(req);
@@ -41,7 +100,7 @@ export class JsonRpcHandler {
     if (Array.isArray(jsonHttpRequest)) {
       const responses = await Promise.all(
         jsonHttpRequest.map((singleReq: any) =>
-          this._handleSingleRequest(singleReq)
+          this._handleSingleRequest(singleReq, undefined, correlationHeader)
         )
       );
 
@@ -49,13 +108,19 @@ export class JsonRpcHandler {
       return;
     }
 
-    const rpcResp = await this._handleSingleRequest(jsonHttpRequest);
+    const rpcResp = await this._handleSingleRequest(
+      jsonHttpRequest,
+      undefined,
+      correlationHeader
+    );
 
     this._sendResponse(res, rpcResp);
   };
 
-  public handleWs = async (ws: WebSocket) => {
+  public handleWs = async (ws: WebSocket, req?: IncomingMessage) => {
+    const correlationHeader = req?.headers[CORRELATION_ID_HEADER];
     const subscriptions: string[] = [];
+    const session = new ConnectionSession();
     let isClosed = false;
 
     const listener = (payload: { subscription: string; result: any }) => {
@@ -90,11 +155,21 @@ export class JsonRpcHandler {
 
         rpcResp = Array.isArray(rpcReq)
           ? await Promise.all(
-              rpcReq.map((req) =>
-                this._handleSingleWsRequest(req, subscriptions)
+              rpcReq.map((singleReq) =>
+                this._handleSingleWsRequest(
+                  singleReq,
+                  subscriptions,
+                  session,
+                  correlationHeader
+                )
               )
             )
-          : await this._handleSingleWsRequest(rpcReq, subscriptions);
+          : await this._handleSingleWsRequest(
+              rpcReq,
+              subscriptions,
+              session,
+              correlationHeader
+            );
       } catch (error) {
         rpcResp = _handleError(error);
       }
@@ -127,6 +202,7 @@ export class JsonRpcHandler {
     res.setHeader("Access-Control-Request-Method", "*");
     res.setHeader("Access-Control-Allow-Methods", "OPTIONS, GET");
     res.setHeader("Access-Control-Allow-Headers", "*");
+    res.setHeader("Access-Control-Expose-Headers", CORRELATION_ID_HEADER);
   }
 
   private _sendResponse(
@@ -139,7 +215,9 @@ export class JsonRpcHandler {
   }
 
   private async _handleSingleRequest(
-    req: JsonRpcRequest
+    req: JsonRpcRequest,
+    session?: ConnectionSession,
+    correlationHeader?: string | string[]
   ): Promise<JsonRpcResponse> {
     if (!isValidJsonRequest(req)) {
       return _handleError(new InvalidRequestError("Invalid request"));
@@ -148,9 +226,21 @@ export class JsonRpcHandler {
     const rpcReq: JsonRpcRequest = req;
     let rpcResp: JsonRpcResponse | undefined;
 
+    const correlationId = getCorrelationId(rpcReq, correlationHeader);
+    const logPrefix = correlationId !== undefined ? `[${correlationId}] ` : "";
+
+    log(`${logPrefix}Handling ${rpcReq.method}`);
+
+    if (correlationId !== undefined) {
+      this._printLine?.(
+        `Request ${rpcReq.method} with correlation id ${correlationId}`
+      );
+    }
+
     try {
-      rpcResp = await this._handleRequest(rpcReq);
+      rpcResp = await this._handleRequest(rpcReq, session);
     } catch (error) {
+      log(`${logPrefix}${rpcReq.method} failed: %O`, error);
       rpcResp = _handleError(error);
     }
 
@@ -169,9 +259,15 @@ export class JsonRpcHandler {
 
   private async _handleSingleWsRequest(
     rpcReq: JsonRpcRequest,
-    subscriptions: string[]
+    subscriptions: string[],
+    session: ConnectionSession,
+    correlationHeader?: string | string[]
   ) {
-    const rpcResp = await this._handleSingleRequest(rpcReq);
+    const rpcResp = await this._handleSingleRequest(
+      rpcReq,
+      session,
+      correlationHeader
+    );
 
     // If eth_subscribe was successful, keep track of the subscription id,
     // so we can cleanup on websocket close.
@@ -186,8 +282,25 @@ export class JsonRpcHandler {
   }
 
   private _handleRequest = async (
-    req: JsonRpcRequest
+    req: JsonRpcRequest,
+    session?: ConnectionSession
   ): Promise<JsonRpcResponse> => {
+    this._methodFilter.assertAllowed(req.method);
+
+    if (session !== undefined) {
+      if (req.method === SET_SESSION_DEFAULTS_METHOD) {
+        return {
+          jsonrpc: "2.0",
+          id: req.id,
+          result: session.setDefaults(req.params ?? []),
+        };
+      }
+
+      req = session.applyDefaults(req);
+    }
+
+    assertServerRequestOptions(req);
+
     const result = await this._provider.request({
       method: req.method,
       params: req.params,
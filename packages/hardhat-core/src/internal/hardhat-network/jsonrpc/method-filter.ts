@@ -0,0 +1,84 @@
+import { MethodNotFoundError } from "../../core/providers/errors";
+
+import { isReadOnlyMethod } from "./read-only-methods";
+
+const FAUCET_METHOD = "hardhat_requestFunds";
+
+// Methods that expose secrets, like the private keys of the accounts. A server
+// only accepts them if they are listed by name in its allowed methods.
+const DEFAULT_DISABLED_METHODS = ["hardhat_deriveAccount"];
+
+export interface MethodFilterConfig {
+  readOnly?: boolean;
+
+  // If true, a read-only filter also accepts the faucet. It should only be
+  // set if the faucet has a rate limit, as it creates ETH.
+  allowFaucet?: boolean;
+
+  // Method names or namespace patterns like "hardhat_*". If present, only the
+  // methods that match one of them are accepted.
+  allowedMethods?: string[];
+
+  // Method names or namespace patterns like "evm_*" that are rejected
+  disabledMethods?: string[];
+}
+
+/**
+ * Decides which JSON-RPC methods a server accepts.
+ */
+export class MethodFilter {
+  constructor(private readonly _config: MethodFilterConfig = {}) {}
+
+  public isAllowed(method: string): boolean {
+    return this._getRejectionReason(method) === undefined;
+  }
+
+  /**
+   * Throws a `MethodNotFoundError` if the method isn't accepted.
+   */
+  public assertAllowed(method: string) {
+    const reason = this._getRejectionReason(method);
+
+    if (reason !== undefined) {
+      // eslint-disable-next-line @nomicfoundation/hardhat-internal-rules/only-hardhat-error
+      throw new MethodNotFoundError(reason);
+    }
+  }
+
+  private _getRejectionReason(method: string): string | undefined {
+    const { readOnly, allowFaucet, allowedMethods, disabledMethods } =
+      this._config;
+
+    if (
+      DEFAULT_DISABLED_METHODS.includes(method) &&
+      allowedMethods?.includes(method) !== true
+    ) {
+      return `Method ${method} is disabled in this server unless it's explicitly allowed`;
+    }
+
+    if (
+      (allowedMethods !== undefined &&
+        !allowedMethods.some((pattern) => matchesPattern(method, pattern))) ||
+      (disabledMethods !== undefined &&
+        disabledMethods.some((pattern) => matchesPattern(method, pattern)))
+    ) {
+      return `Method ${method} is disabled in this server`;
+    }
+
+    if (
+      readOnly === true &&
+      !isReadOnlyMethod(method) &&
+      !(allowFaucet === true && method === FAUCET_METHOD)
+    ) {
+      return `Method ${method} is not available in a read-only server`;
+    }
+  }
+}
+
+function matchesPattern(method: string, pattern: string): boolean {
+  if (pattern.endsWith("*")) {
+    return method.startsWith(pattern.slice(0, -1));
+  }
+
+  return method === pattern;
+}
@@ -0,0 +1,58 @@
+import { SET_SESSION_DEFAULTS_METHOD } from "./session";
+
+// Methods that a read-only server accepts besides the `eth_get*` ones. They
+// can't modify the chain, the mempool or the node's configuration, and they
+// don't sign anything with the node's accounts.
+const READ_ONLY_METHODS = new Set([
+  "debug_disassemble",
+  "debug_getRawBlock",
+  "debug_getRawReceipts",
+  "debug_getRawTransaction",
+  "debug_traceBlockByHash",
+  "debug_traceBlockByNumber",
+  "debug_traceCall",
+  "debug_traceTransaction",
+  "eth_accounts",
+  "eth_blockNumber",
+  "eth_call",
+  "eth_chainId",
+  "eth_coinbase",
+  "eth_estimateGas",
+  "eth_feeHistory",
+  "eth_gasPrice",
+  "eth_maxPriorityFeePerGas",
+  "eth_mining",
+  "eth_newBlockFilter",
+  "eth_newFilter",
+  "eth_newPendingTransactionFilter",
+  "eth_pendingTransactions",
+  "eth_subscribe",
+  "eth_syncing",
+  "eth_uninstallFilter",
+  "eth_unsubscribe",
+  "hardhat_diffCalls",
+  "hardhat_getAutomine",
+  "hardhat_getLogStatistics",
+  "hardhat_getTransactionDiagnostics",
+  "hardhat_getTransactionReplacement",
+  "hardhat_metadata",
+  "hardhat_multicall",
+  "hardhat_recoverSigner",
+  "hardhat_validateRawTransaction",
+  "hardhat_verifyTypedData",
+  "net_listening",
+  "net_peerCount",
+  "net_version",
+  "trace_block",
+  "trace_transaction",
+  "txpool_content",
+  "txpool_inspect",
+  "txpool_status",
+  "web3_clientVersion",
+  "web3_sha3",
+  SET_SESSION_DEFAULTS_METHOD,
+]);
+
+export function isReadOnlyMethod(method: string): boolean {
+  return method.startsWith("eth_get") || READ_ONLY_METHODS.has(method);
+}
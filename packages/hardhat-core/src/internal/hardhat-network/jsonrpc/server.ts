@@ -10,8 +10,10 @@ import {
   JsonRpcServer as IJsonRpcServer,
 } from "../../../types";
 import { HttpProvider } from "../../core/providers/http";
+import { printLine } from "../provider/modules/logger";
 
 import { JsonRpcHandler } from "./handler";
+import { MethodFilter } from "./method-filter";
 
 const log = debug("hardhat:core:hardhat-network:jsonrpc");
 
@@ -20,6 +22,25 @@ export interface JsonRpcServerConfig {
   port: number;
 
   provider: EIP1193Provider;
+
+  // If true, the server rejects every method that can modify the chain or the
+  // node, so that it can be shared with untrusted consumers
+  readOnly?: boolean;
+
+  // If true, a read-only server also accepts hardhat_requestFunds, which
+  // should only be exposed if the faucet has a rate limit
+  allowFaucet?: boolean;
+
+  // Method names or namespace patterns like "hardhat_*". If set, every other
+  // method is rejected.
+  allowedMethods?: string[];
+
+  // Method names or namespace patterns like "evm_*" that the server rejects
+  disabledMethods?: string[];
+
+  // If true, the correlation id of each request that has one is printed to
+  // the console, along with the logs of the node
+  logCorrelationIds?: boolean;
 }
 
 export class JsonRpcServer implements IJsonRpcServer {
@@ -32,7 +53,16 @@ export class JsonRpcServer implements IJsonRpcServer {
 
     this._config = config;
 
-    const handler = new JsonRpcHandler(config.provider);
+    const handler = new JsonRpcHandler(
+      config.provider,
+      new MethodFilter({
+        readOnly: config.readOnly,
+        allowFaucet: config.allowFaucet,
+        allowedMethods: config.allowedMethods,
+        disabledMethods: config.disabledMethods,
+      }),
+      config.logCorrelationIds === true ? printLine : undefined
+    );
 
     this._httpServer = http.createServer();
     this._wsServer = new WSServer({
@@ -0,0 +1,110 @@
+import * as t from "io-ts";
+
+import {
+  bufferToRpcData,
+  numberToRpcQuantity,
+  rpcAddress,
+} from "../../core/jsonrpc/types/base-types";
+import { rpcOldBlockTag } from "../../core/jsonrpc/types/input/blockTag";
+import { validateParams } from "../../core/jsonrpc/types/input/validation";
+import { optionalOrNullable } from "../../util/io-ts";
+import { JsonRpcRequest } from "../../util/jsonrpc";
+
+export const SET_SESSION_DEFAULTS_METHOD = "hardhat_setSessionDefaults";
+
+const rpcSessionDefaults = t.type(
+  {
+    from: optionalOrNullable(rpcAddress),
+    blockTag: optionalOrNullable(rpcOldBlockTag),
+    // Any JSON value, like in eth_sendTransaction
+    tag: optionalOrNullable(t.unknown),
+  },
+  "RpcSessionDefaults"
+);
+
+// Methods whose first param is a transaction or call object
+const METHODS_WITH_FROM = new Set([
+  "debug_traceCall",
+  "eth_call",
+  "eth_estimateGas",
+  "eth_sendTransaction",
+]);
+
+// Position of the optional block tag param of each method that has one
+const BLOCK_TAG_PARAM_INDEX: { [method: string]: number } = {
+  debug_traceCall: 1,
+  eth_call: 1,
+  eth_estimateGas: 1,
+  eth_getBalance: 1,
+  eth_getCode: 1,
+  eth_getStorageAt: 2,
+  eth_getTransactionCount: 1,
+};
+
+/**
+ * Per-connection defaults set by a client through
+ * `hardhat_setSessionDefaults`. They are only applied to the requests of the
+ * connection that set them, and only to params the request left empty.
+ */
+export class ConnectionSession {
+  private _from?: string;
+  private _blockTag?: string;
+  private _tag?: unknown;
+
+  public setDefaults(params: any[]): boolean {
+    const [defaults] = validateParams(params, rpcSessionDefaults);
+
+    this._from =
+      defaults.from !== undefined ? bufferToRpcData(defaults.from) : undefined;
+
+    this._blockTag =
+      typeof defaults.blockTag === "bigint"
+        ? numberToRpcQuantity(defaults.blockTag)
+        : defaults.blockTag;
+
+    this._tag = defaults.tag;
+
+    return true;
+  }
+
+  public applyDefaults(req: JsonRpcRequest): JsonRpcRequest {
+    if (req.params !== undefined && !Array.isArray(req.params)) {
+      return req;
+    }
+
+    const params = [...(req.params ?? [])];
+
+    if (
+      this._from !== undefined &&
+      METHODS_WITH_FROM.has(req.method) &&
+      typeof params[0] === "object" &&
+      params[0] !== null &&
+      params[0].from === undefined
+    ) {
+      params[0] = { ...params[0], from: this._from };
+    }
+
+    // The tag labels the transactions sent through the connection, like the
+    // tag field of eth_sendTransaction
+    if (
+      this._tag !== undefined &&
+      req.method === "eth_sendTransaction" &&
+      typeof params[0] === "object" &&
+      params[0] !== null &&
+      !("tag" in params[0])
+    ) {
+      params[0] = { ...params[0], tag: this._tag };
+    }
+
+    const blockTagIndex = BLOCK_TAG_PARAM_INDEX[req.method];
+    if (
+      this._blockTag !== undefined &&
+      blockTagIndex !== undefined &&
+      params.length === blockTagIndex
+    ) {
+      params.push(this._blockTag);
+    }
+
+    return { ...req, params };
+  }
+}
@@ -0,0 +1,48 @@
+import type { EIP1193Provider } from "../../../../types";
+
+import { validateParams } from "../../../core/jsonrpc/types/input/validation";
+import { MethodNotFoundError } from "../../../core/providers/errors";
+import {
+  getBlockReceipts,
+  RpcBlockHashOrTag,
+  rpcBlockHashOrTag,
+} from "../utils/blocks";
+
+import { ProviderModule } from "./module";
+
+/* eslint-disable @nomicfoundation/hardhat-internal-rules/only-hardhat-error */
+
+export class BlockReceiptsModule implements ProviderModule {
+  constructor(private readonly _provider: EIP1193Provider) {}
+
+  public handles(method: string): boolean {
+    return method === "eth_getBlockReceipts";
+  }
+
+  public async processRequest(method: string, params: any[]): Promise<unknown> {
+    switch (method) {
+      case "eth_getBlockReceipts":
+        return this._getBlockReceiptsAction(
+          ...this._getBlockReceiptsParams(params)
+        );
+    }
+
+    throw new MethodNotFoundError(`Method ${method} not found`);
+  }
+
+  // eth_getBlockReceipts
+
+  private _getBlockReceiptsParams(params: any[]): [RpcBlockHashOrTag] {
+    return validateParams(params, rpcBlockHashOrTag);
+  }
+
+  private async _getBlockReceiptsAction(
+    blockHashOrTag: RpcBlockHashOrTag
+  ): Promise<unknown[] | null> {
+    return getBlockReceipts(
+      this._provider,
+      "eth_getBlockReceipts",
+      blockHashOrTag
+    );
+  }
+}
@@ -0,0 +1,108 @@
+import type { EIP1193Provider } from "../../../../types";
+
+import {
+  bufferToRpcData,
+  numberToRpcQuantity,
+  rpcHash,
+} from "../../../core/jsonrpc/types/base-types";
+import {
+  RpcOldBlockTag,
+  rpcOldBlockTag,
+} from "../../../core/jsonrpc/types/input/blockTag";
+import {
+  RpcDebugTracingConfig,
+  rpcDebugTracingConfig,
+} from "../../../core/jsonrpc/types/input/debugTraceTransaction";
+import { validateParams } from "../../../core/jsonrpc/types/input/validation";
+import {
+  InvalidArgumentsError,
+  MethodNotFoundError,
+} from "../../../core/providers/errors";
+import { BlockTransactionTrace, traceBlock } from "../utils/blocks";
+
+import { ProviderModule } from "./module";
+
+/* eslint-disable @nomicfoundation/hardhat-internal-rules/only-hardhat-error */
+
+export class DebugTraceBlockModule implements ProviderModule {
+  constructor(private readonly _provider: EIP1193Provider) {}
+
+  public handles(method: string): boolean {
+    return (
+      method === "debug_traceBlockByNumber" ||
+      method === "debug_traceBlockByHash"
+    );
+  }
+
+  public async processRequest(method: string, params: any[]): Promise<unknown> {
+    switch (method) {
+      case "debug_traceBlockByNumber":
+        return this._traceBlockByNumberAction(
+          ...this._traceBlockByNumberParams(params)
+        );
+      case "debug_traceBlockByHash":
+        return this._traceBlockByHashAction(
+          ...this._traceBlockByHashParams(params)
+        );
+    }
+
+    throw new MethodNotFoundError(`Method ${method} not found`);
+  }
+
+  // debug_traceBlockByNumber
+
+  private _traceBlockByNumberParams(
+    params: any[]
+  ): [RpcOldBlockTag, RpcDebugTracingConfig] {
+    return validateParams(params, rpcOldBlockTag, rpcDebugTracingConfig);
+  }
+
+  private async _traceBlockByNumberAction(
+    blockTag: RpcOldBlockTag,
+    config: RpcDebugTracingConfig
+  ): Promise<BlockTransactionTrace[]> {
+    if (blockTag === "pending") {
+      throw new InvalidArgumentsError(
+        "debug_traceBlockByNumber doesn't support the pending block"
+      );
+    }
+
+    const blockNumber =
+      typeof blockTag === "bigint" ? numberToRpcQuantity(blockTag) : blockTag;
+
+    return traceBlock(
+      this._provider,
+      await this._provider.request({
+        method: "eth_getBlockByNumber",
+        params: [blockNumber, false],
+      }),
+      blockNumber,
+      config
+    );
+  }
+
+  // debug_traceBlockByHash
+
+  private _traceBlockByHashParams(
+    params: any[]
+  ): [Buffer, RpcDebugTracingConfig] {
+    return validateParams(params, rpcHash, rpcDebugTracingConfig);
+  }
+
+  private async _traceBlockByHashAction(
+    hash: Buffer,
+    config: RpcDebugTracingConfig
+  ): Promise<BlockTransactionTrace[]> {
+    const blockHash = bufferToRpcData(hash);
+
+    return traceBlock(
+      this._provider,
+      await this._provider.request({
+        method: "eth_getBlockByHash",
+        params: [blockHash, false],
+      }),
+      blockHash,
+      config
+    );
+  }
+}
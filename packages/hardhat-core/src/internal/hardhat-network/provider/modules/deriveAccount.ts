@@ -0,0 +1,81 @@
+import type { HardhatNetworkHDAccountsConfig } from "../../../../types";
+import type EthereumjsUtilT from "@nomicfoundation/ethereumjs-util";
+
+import * as t from "io-ts";
+
+import { bufferToRpcData } from "../../../core/jsonrpc/types/base-types";
+import { validateParams } from "../../../core/jsonrpc/types/input/validation";
+import {
+  InvalidArgumentsError,
+  InvalidInputError,
+  MethodNotFoundError,
+} from "../../../core/providers/errors";
+import { derivePrivateKeys } from "../../../core/providers/util";
+
+import { ProviderModule } from "./module";
+
+/* eslint-disable @nomicfoundation/hardhat-internal-rules/only-hardhat-error */
+
+export class DeriveAccountModule implements ProviderModule {
+  constructor(
+    // Only present if the accounts are derived from a mnemonic
+    private readonly _hdAccounts: HardhatNetworkHDAccountsConfig | undefined,
+    private readonly _checksumAddresses: boolean
+  ) {}
+
+  public handles(method: string): boolean {
+    return method === "hardhat_deriveAccount";
+  }
+
+  public async processRequest(method: string, params: any[]): Promise<unknown> {
+    switch (method) {
+      case "hardhat_deriveAccount":
+        return this._deriveAccountAction(...this._deriveAccountParams(params));
+    }
+
+    throw new MethodNotFoundError(`Method ${method} not found`);
+  }
+
+  // hardhat_deriveAccount
+
+  private _deriveAccountParams(params: any[]): [number] {
+    return validateParams(params, t.number);
+  }
+
+  private _deriveAccountAction(index: number): {
+    address: string;
+    privateKey: string;
+  } {
+    if (this._hdAccounts === undefined) {
+      throw new InvalidInputError(
+        "hardhat_deriveAccount can only be used if the accounts are derived from a mnemonic"
+      );
+    }
+
+    // Indexes from 2^31 on are used by hardened derivation
+    if (!Number.isInteger(index) || index < 0 || index >= 2 ** 31) {
+      throw new InvalidArgumentsError(
+        "The index of the account must be an integer between 0 and 2^31 - 1"
+      );
+    }
+
+    const { privateToAddress, toChecksumAddress } =
+      require("@nomicfoundation/ethereumjs-util") as typeof EthereumjsUtilT;
+
+    const { mnemonic, path, passphrase } = this._hdAccounts;
+    const [privateKey] = derivePrivateKeys(
+      mnemonic,
+      path,
+      index,
+      1,
+      passphrase
+    );
+
+    const address = bufferToRpcData(privateToAddress(privateKey));
+
+    return {
+      address: this._checksumAddresses ? toChecksumAddress(address) : address,
+      privateKey: bufferToRpcData(privateKey),
+    };
+  }
+}
@@ -0,0 +1,83 @@
+import type { EIP1193Provider } from "../../../../types";
+
+import { validateParams } from "../../../core/jsonrpc/types/input/validation";
+import {
+  InvalidArgumentsError,
+  MethodNotFoundError,
+} from "../../../core/providers/errors";
+import { RpcDebugTraceOutput } from "../output";
+import { newBlockTagToRpc } from "../utils/blockTags";
+import {
+  CallExecutionDiff,
+  diffCallExecutions,
+  RpcDiffedCall,
+  rpcDiffedCall,
+  summarizeCallTrace,
+} from "../utils/callDiff";
+
+import { ProviderModule } from "./module";
+
+/* eslint-disable @nomicfoundation/hardhat-internal-rules/only-hardhat-error */
+
+export class DiffCallsModule implements ProviderModule {
+  constructor(private readonly _provider: EIP1193Provider) {}
+
+  public handles(method: string): boolean {
+    return method === "hardhat_diffCalls";
+  }
+
+  public async processRequest(method: string, params: any[]): Promise<unknown> {
+    switch (method) {
+      case "hardhat_diffCalls":
+        return this._diffCallsAction(...this._diffCallsParams(params));
+    }
+
+    throw new MethodNotFoundError(`Method ${method} not found`);
+  }
+
+  // hardhat_diffCalls
+
+  private _diffCallsParams(params: any[]): [RpcDiffedCall, RpcDiffedCall] {
+    return validateParams(params, rpcDiffedCall, rpcDiffedCall);
+  }
+
+  private async _diffCallsAction(
+    first: RpcDiffedCall,
+    second: RpcDiffedCall
+  ): Promise<CallExecutionDiff> {
+    const firstExecution = await this._executeDiffedCall(first);
+    const secondExecution = await this._executeDiffedCall(second);
+
+    return diffCallExecutions(firstExecution, secondExecution);
+  }
+
+  private async _executeDiffedCall({
+    call,
+    blockTag,
+    stateOverrides,
+  }: RpcDiffedCall) {
+    if (stateOverrides !== undefined) {
+      throw new InvalidArgumentsError(
+        "hardhat_diffCalls doesn't support state overrides"
+      );
+    }
+
+    const to = (call as { to?: unknown } | undefined)?.to;
+    if (typeof to !== "string") {
+      throw new InvalidArgumentsError(
+        "hardhat_diffCalls doesn't support contract creations"
+      );
+    }
+
+    const trace = (await this._provider.request({
+      method: "debug_traceCall",
+      params: [
+        call,
+        newBlockTagToRpc(blockTag ?? "latest"),
+        { disableStorage: true },
+      ],
+    })) as RpcDebugTraceOutput;
+
+    return summarizeCallTrace(trace, to);
+  }
+}
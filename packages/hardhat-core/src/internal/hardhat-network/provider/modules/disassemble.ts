@@ -0,0 +1,81 @@
+import type { Common } from "@nomicfoundation/ethereumjs-common";
+import type {
+  EIP1193Provider,
+  HardhatNetworkChainsConfig,
+} from "../../../../types";
+
+import {
+  bufferToRpcData,
+  rpcDataToBuffer,
+} from "../../../core/jsonrpc/types/base-types";
+import {
+  OptionalRpcNewBlockTag,
+  optionalRpcNewBlockTag,
+} from "../../../core/jsonrpc/types/input/blockTag";
+import { validateParams } from "../../../core/jsonrpc/types/input/validation";
+import { MethodNotFoundError } from "../../../core/providers/errors";
+import { newBlockTagToRpc } from "../utils/blockTags";
+import { getBlockCommon } from "../utils/blocks";
+import {
+  Disassembly,
+  disassemble,
+  RpcDisassemblyTarget,
+  rpcDisassemblyTarget,
+} from "../utils/disassemble";
+
+import { ProviderModule } from "./module";
+
+/* eslint-disable @nomicfoundation/hardhat-internal-rules/only-hardhat-error */
+
+export class DisassembleModule implements ProviderModule {
+  constructor(
+    private readonly _provider: EIP1193Provider,
+    private readonly _common: Common,
+    private readonly _chains: HardhatNetworkChainsConfig
+  ) {}
+
+  public handles(method: string): boolean {
+    return method === "debug_disassemble";
+  }
+
+  public async processRequest(method: string, params: any[]): Promise<unknown> {
+    switch (method) {
+      case "debug_disassemble":
+        return this._disassembleAction(...this._disassembleParams(params));
+    }
+
+    throw new MethodNotFoundError(`Method ${method} not found`);
+  }
+
+  // debug_disassemble
+
+  private _disassembleParams(
+    params: any[]
+  ): [RpcDisassemblyTarget, OptionalRpcNewBlockTag] {
+    return validateParams(params, rpcDisassemblyTarget, optionalRpcNewBlockTag);
+  }
+
+  private async _disassembleAction(
+    target: RpcDisassemblyTarget,
+    blockTag: OptionalRpcNewBlockTag
+  ): Promise<Disassembly> {
+    const block = blockTag ?? "latest";
+
+    let code: Buffer;
+    if ("address" in target) {
+      const rpcCode = await this._provider.request({
+        method: "eth_getCode",
+        params: [bufferToRpcData(target.address), newBlockTagToRpc(block)],
+      });
+
+      code = rpcDataToBuffer(rpcCode as string);
+    } else {
+      code = target.code;
+    }
+
+    return disassemble(
+      code,
+      await getBlockCommon(this._provider, this._common, this._chains, block)
+    );
+  }
+}
@@ -0,0 +1,165 @@
+import type { EIP1193Provider } from "../../../../types";
+
+import { numberToRpcQuantity } from "../../../core/jsonrpc/types/base-types";
+import { validateParams } from "../../../core/jsonrpc/types/input/validation";
+import {
+  InvalidArgumentsError,
+  InvalidInputError,
+  MethodNotFoundError,
+} from "../../../core/providers/errors";
+import {
+  FixedGasPriceOracle,
+  GasPriceOracle,
+  PercentileGasPriceOracle,
+  PercentileGasPriceOracleConfig,
+  RemoteGasPriceOracle,
+  RpcGasPriceOracleConfig,
+  rpcGasPriceOracleConfig,
+} from "../utils/gasPriceOracle";
+
+import { ProviderModule } from "./module";
+
+/* eslint-disable @nomicfoundation/hardhat-internal-rules/only-hardhat-error */
+
+export class GasPriceOracleModule implements ProviderModule {
+  // If set, it replaces EDR's suggestions for eth_gasPrice and
+  // eth_maxPriorityFeePerGas. It starts as the one of the config, if any.
+  private _gasPriceOracle?: GasPriceOracle;
+
+  constructor(
+    private readonly _provider: EIP1193Provider,
+    private readonly _config: PercentileGasPriceOracleConfig | undefined,
+    private readonly _getForkProvider: () => EIP1193Provider | undefined
+  ) {
+    this._gasPriceOracle = this._createConfiguredGasPriceOracle();
+  }
+
+  public handles(method: string): boolean {
+    if (method === "hardhat_setGasPriceOracle") {
+      return true;
+    }
+
+    // EDR suggests the fees if there's no oracle
+    return (
+      (method === "eth_gasPrice" || method === "eth_maxPriorityFeePerGas") &&
+      this._gasPriceOracle !== undefined
+    );
+  }
+
+  public async processRequest(method: string, params: any[]): Promise<unknown> {
+    switch (method) {
+      case "hardhat_setGasPriceOracle":
+        return this._setGasPriceOracleAction(
+          ...this._setGasPriceOracleParams(params)
+        );
+      case "eth_gasPrice":
+        return this._gasPriceAction(...this._gasPriceParams(params));
+      case "eth_maxPriorityFeePerGas":
+        return this._maxPriorityFeePerGasAction(
+          ...this._maxPriorityFeePerGasParams(params)
+        );
+    }
+
+    throw new MethodNotFoundError(`Method ${method} not found`);
+  }
+
+  public reset() {
+    this._gasPriceOracle = this._createConfiguredGasPriceOracle();
+  }
+
+  // hardhat_setGasPriceOracle
+
+  private _setGasPriceOracleParams(
+    params: any[]
+  ): [RpcGasPriceOracleConfig] {
+    return validateParams(params, rpcGasPriceOracleConfig);
+  }
+
+  private _setGasPriceOracleAction(config: RpcGasPriceOracleConfig): boolean {
+    switch (config.strategy) {
+      case "default":
+        this._gasPriceOracle = this._createConfiguredGasPriceOracle();
+        break;
+      case "fixed":
+        this._gasPriceOracle = new FixedGasPriceOracle(
+          config.gasPrice,
+          config.maxPriorityFeePerGas
+        );
+        break;
+      case "percentile":
+        if (
+          !Number.isInteger(config.blocks) ||
+          config.blocks < 1 ||
+          config.blocks > 1024
+        ) {
+          throw new InvalidArgumentsError(
+            "The number of blocks of the percentile strategy must be an integer between 1 and 1024"
+          );
+        }
+
+        if (config.percentile < 0 || config.percentile > 100) {
+          throw new InvalidArgumentsError(
+            "The percentile of the percentile strategy must be between 0 and 100"
+          );
+        }
+
+        this._gasPriceOracle = new PercentileGasPriceOracle(
+          this._provider,
+          config.blocks,
+          config.percentile,
+          config.fallbackMaxPriorityFeePerGas
+        );
+        break;
+      case "remote": {
+        const forkProvider = this._getForkProvider();
+        if (forkProvider === undefined) {
+          throw new InvalidInputError(
+            "The remote strategy can only be used when forking"
+          );
+        }
+
+        this._gasPriceOracle = new RemoteGasPriceOracle(forkProvider);
+        break;
+      }
+    }
+
+    return true;
+  }
+
+  // eth_gasPrice
+
+  private _gasPriceParams(params: any[]): [] {
+    return validateParams(params);
+  }
+
+  private async _gasPriceAction(): Promise<string> {
+    return numberToRpcQuantity(await this._gasPriceOracle!.getGasPrice());
+  }
+
+  // eth_maxPriorityFeePerGas
+
+  private _maxPriorityFeePerGasParams(params: any[]): [] {
+    return validateParams(params);
+  }
+
+  private async _maxPriorityFeePerGasAction(): Promise<string> {
+    return numberToRpcQuantity(
+      await this._gasPriceOracle!.getMaxPriorityFeePerGas()
+    );
+  }
+
+  private _createConfiguredGasPriceOracle(): GasPriceOracle | undefined {
+    if (this._config === undefined) {
+      return undefined;
+    }
+
+    const { blocks, percentile, fallbackMaxPriorityFeePerGas } = this._config;
+
+    return new PercentileGasPriceOracle(
+      this._provider,
+      blocks,
+      percentile,
+      fallbackMaxPriorityFeePerGas
+    );
+  }
+}
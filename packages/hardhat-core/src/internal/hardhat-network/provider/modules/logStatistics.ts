@@ -0,0 +1,54 @@
+import type { EIP1193Provider } from "../../../../types";
+
+import { numberToRpcQuantity } from "../../../core/jsonrpc/types/base-types";
+import { validateParams } from "../../../core/jsonrpc/types/input/validation";
+import { MethodNotFoundError } from "../../../core/providers/errors";
+import { computeLogStatistics, LogStatistics } from "../utils/logStatistics";
+
+import { ProviderModule } from "./module";
+
+/* eslint-disable @nomicfoundation/hardhat-internal-rules/only-hardhat-error */
+
+export class LogStatisticsModule implements ProviderModule {
+  constructor(private readonly _provider: EIP1193Provider) {}
+
+  public handles(method: string): boolean {
+    return method === "hardhat_getLogStatistics";
+  }
+
+  public async processRequest(method: string, params: any[]): Promise<unknown> {
+    switch (method) {
+      case "hardhat_getLogStatistics":
+        return this._getLogStatisticsAction(
+          ...this._getLogStatisticsParams(params)
+        );
+    }
+
+    throw new MethodNotFoundError(`Method ${method} not found`);
+  }
+
+  // hardhat_getLogStatistics
+
+  private _getLogStatisticsParams(params: any[]): [] {
+    return validateParams(params);
+  }
+
+  private async _getLogStatisticsAction(): Promise<LogStatistics> {
+    // Only the local blocks are included when forking
+    const { forkedNetwork } = (await this._provider.request({
+      method: "hardhat_metadata",
+    })) as { forkedNetwork?: { forkBlockNumber: number } };
+
+    const fromBlock =
+      forkedNetwork !== undefined
+        ? numberToRpcQuantity(forkedNetwork.forkBlockNumber + 1)
+        : "earliest";
+
+    const logs = await this._provider.request({
+      method: "eth_getLogs",
+      params: [{ fromBlock, toBlock: "latest" }],
+    });
+
+    return computeLogStatistics(logs as any[]);
+  }
+}
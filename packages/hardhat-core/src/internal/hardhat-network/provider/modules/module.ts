@@ -0,0 +1,17 @@
+/**
+ * A group of JSON-RPC methods that Hardhat Network handles itself instead of
+ * sending them to EDR.
+ */
+export interface ProviderModule {
+  /**
+   * Returns true if the module handles the requests of `method`.
+   */
+  handles(method: string): boolean;
+
+  processRequest(method: string, params: any[]): Promise<unknown>;
+
+  /**
+   * Clears the state of the module after a `hardhat_reset`.
+   */
+  reset?(): void;
+}
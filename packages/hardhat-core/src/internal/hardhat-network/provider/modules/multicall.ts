@@ -0,0 +1,111 @@
+import type { EIP1193Provider } from "../../../../types";
+
+import * as t from "io-ts";
+
+import {
+  OptionalRpcNewBlockTag,
+  optionalRpcNewBlockTag,
+} from "../../../core/jsonrpc/types/input/blockTag";
+import { validateParams } from "../../../core/jsonrpc/types/input/validation";
+import { MethodNotFoundError } from "../../../core/providers/errors";
+import { RpcDebugTraceOutput } from "../output";
+import { newBlockTagToRpc } from "../utils/blockTags";
+import {
+  getFailedCallReturnData,
+  getMulticallResult,
+  MulticallResult,
+  OptionalRpcMulticallOptions,
+  optionalRpcMulticallOptions,
+} from "../utils/multicall";
+
+import { ProviderModule } from "./module";
+
+/* eslint-disable @nomicfoundation/hardhat-internal-rules/only-hardhat-error */
+
+export class MulticallModule implements ProviderModule {
+  constructor(private readonly _provider: EIP1193Provider) {}
+
+  public handles(method: string): boolean {
+    return method === "hardhat_multicall";
+  }
+
+  public async processRequest(method: string, params: any[]): Promise<unknown> {
+    switch (method) {
+      case "hardhat_multicall":
+        return this._multicallAction(...this._multicallParams(params));
+    }
+
+    throw new MethodNotFoundError(`Method ${method} not found`);
+  }
+
+  // hardhat_multicall
+
+  private _multicallParams(
+    params: any[]
+  ): [unknown[], OptionalRpcNewBlockTag, OptionalRpcMulticallOptions] {
+    return validateParams(
+      params,
+      t.array(t.unknown),
+      optionalRpcNewBlockTag,
+      optionalRpcMulticallOptions
+    );
+  }
+
+  private async _multicallAction(
+    calls: unknown[],
+    blockTag: OptionalRpcNewBlockTag,
+    options: OptionalRpcMulticallOptions
+  ): Promise<MulticallResult[]> {
+    // Tags that move with the chain are resolved first, so that every call
+    // runs against the same block even if new ones are mined in between
+    let block = newBlockTagToRpc(blockTag ?? "latest");
+    if (block === "latest" || block === "safe" || block === "finalized") {
+      const { number } = (await this._provider.request({
+        method: "eth_getBlockByNumber",
+        params: [block, false],
+      })) as { number: string };
+
+      block = number;
+    }
+
+    // Any error other than a failed call, like an unknown block, fails the
+    // whole request
+    const results: MulticallResult[] = [];
+    for (const call of calls) {
+      if (options?.trace === true) {
+        const trace = (await this._provider.request({
+          method: "debug_traceCall",
+          params: [
+            call,
+            block,
+            { disableStack: true, disableMemory: true, disableStorage: true },
+          ],
+        })) as RpcDebugTraceOutput;
+
+        results.push(
+          getMulticallResult(`0x${trace.returnValue}`, trace.failed)
+        );
+        continue;
+      }
+
+      // eth_call only throws on failures if throwOnCallFailures is enabled
+      try {
+        const returnData = (await this._provider.request({
+          method: "eth_call",
+          params: [call, block],
+        })) as string;
+
+        results.push(getMulticallResult(returnData, false));
+      } catch (error) {
+        const returnData = getFailedCallReturnData(error);
+        if (returnData === undefined) {
+          throw error;
+        }
+
+        results.push(getMulticallResult(returnData, true));
+      }
+    }
+
+    return results;
+  }
+}
@@ -0,0 +1,115 @@
+import type { EIP1193Provider } from "../../../../types";
+
+import {
+  bufferToRpcData,
+  rpcHash,
+} from "../../../core/jsonrpc/types/base-types";
+import { validateParams } from "../../../core/jsonrpc/types/input/validation";
+import { MethodNotFoundError } from "../../../core/providers/errors";
+import {
+  getBlockByHashOrTag,
+  getBlockReceipts,
+  RpcBlockHashOrTag,
+  rpcBlockHashOrTag,
+} from "../utils/blocks";
+import { encodeRpcBlock, encodeRpcReceipts } from "../utils/rawBlock";
+import { encodeRpcTransaction } from "../utils/rawTransaction";
+
+import { ProviderModule } from "./module";
+
+/* eslint-disable @nomicfoundation/hardhat-internal-rules/only-hardhat-error */
+
+export class RawDataModule implements ProviderModule {
+  constructor(private readonly _provider: EIP1193Provider) {}
+
+  public handles(method: string): boolean {
+    return (
+      method === "debug_getRawBlock" ||
+      method === "debug_getRawReceipts" ||
+      method === "debug_getRawTransaction"
+    );
+  }
+
+  public async processRequest(method: string, params: any[]): Promise<unknown> {
+    switch (method) {
+      case "debug_getRawBlock":
+        return this._getRawBlockAction(...this._getRawBlockParams(params));
+      case "debug_getRawReceipts":
+        return this._getRawReceiptsAction(
+          ...this._getRawReceiptsParams(params)
+        );
+      case "debug_getRawTransaction":
+        return this._getRawTransactionAction(
+          ...this._getRawTransactionParams(params)
+        );
+    }
+
+    throw new MethodNotFoundError(`Method ${method} not found`);
+  }
+
+  // debug_getRawBlock
+
+  private _getRawBlockParams(params: any[]): [RpcBlockHashOrTag] {
+    return validateParams(params, rpcBlockHashOrTag);
+  }
+
+  private async _getRawBlockAction(
+    blockHashOrTag: RpcBlockHashOrTag
+  ): Promise<string | null> {
+    const block = await getBlockByHashOrTag(
+      this._provider,
+      "debug_getRawBlock",
+      blockHashOrTag,
+      true
+    );
+
+    if (block === null) {
+      return null;
+    }
+
+    return encodeRpcBlock(block);
+  }
+
+  // debug_getRawReceipts
+
+  private _getRawReceiptsParams(params: any[]): [RpcBlockHashOrTag] {
+    return validateParams(params, rpcBlockHashOrTag);
+  }
+
+  private async _getRawReceiptsAction(
+    blockHashOrTag: RpcBlockHashOrTag
+  ): Promise<string[] | null> {
+    const receipts = await getBlockReceipts(
+      this._provider,
+      "debug_getRawReceipts",
+      blockHashOrTag
+    );
+
+    if (receipts === null) {
+      return null;
+    }
+
+    return encodeRpcReceipts(receipts);
+  }
+
+  // debug_getRawTransaction
+
+  private _getRawTransactionParams(params: any[]): [Buffer] {
+    return validateParams(params, rpcHash);
+  }
+
+  private async _getRawTransactionAction(
+    hash: Buffer
+  ): Promise<string | null> {
+    const tx = await this._provider.request({
+      method: "eth_getTransactionByHash",
+      params: [bufferToRpcData(hash)],
+    });
+
+    if (tx === null) {
+      return null;
+    }
+
+    return encodeRpcTransaction(tx);
+  }
+}
@@ -0,0 +1,87 @@
+import type { EIP1193Provider } from "../../../../types";
+
+import * as t from "io-ts";
+
+import {
+  bufferToRpcData,
+  rpcAddress,
+} from "../../../core/jsonrpc/types/base-types";
+import { validateParams } from "../../../core/jsonrpc/types/input/validation";
+import { MethodNotFoundError } from "../../../core/providers/errors";
+
+import { ProviderModule } from "./module";
+
+/* eslint-disable @nomicfoundation/hardhat-internal-rules/only-hardhat-error */
+
+export class RejectFailedTransactionsModule implements ProviderModule {
+  // Senders whose transactions are rejected instead of mined if they fail
+  private readonly _rejectFailedTransactionsFrom = new Set<string>();
+
+  constructor(private readonly _provider: EIP1193Provider) {}
+
+  public handles(method: string): boolean {
+    return method === "hardhat_setRejectFailedTransactions";
+  }
+
+  public async processRequest(method: string, params: any[]): Promise<unknown> {
+    switch (method) {
+      case "hardhat_setRejectFailedTransactions":
+        return this._setRejectFailedTransactionsAction(
+          ...this._setRejectFailedTransactionsParams(params)
+        );
+    }
+
+    throw new MethodNotFoundError(`Method ${method} not found`);
+  }
+
+  public reset() {
+    this._rejectFailedTransactionsFrom.clear();
+  }
+
+  /**
+   * Estimates the gas of an `eth_sendTransaction` request against the pending
+   * block if its sender is configured to have its failed transactions
+   * rejected. The estimation throws the same error that the transaction would
+   * fail with, so the transaction is never sent.
+   */
+  public async rejectIfFailing(params: any[]) {
+    const [tx] = params;
+
+    if (
+      typeof tx?.from !== "string" ||
+      !this._rejectFailedTransactionsFrom.has(tx.from.toLowerCase())
+    ) {
+      return;
+    }
+
+    const { nonce: _nonce, ...callRequest } = tx;
+
+    await this._provider.request({
+      method: "eth_estimateGas",
+      params: [callRequest, "pending"],
+    });
+  }
+
+  // hardhat_setRejectFailedTransactions
+
+  private _setRejectFailedTransactionsParams(
+    params: any[]
+  ): [Buffer, boolean] {
+    return validateParams(params, rpcAddress, t.boolean);
+  }
+
+  private _setRejectFailedTransactionsAction(
+    address: Buffer,
+    enabled: boolean
+  ): boolean {
+    const sender = bufferToRpcData(address);
+
+    if (enabled) {
+      this._rejectFailedTransactionsFrom.add(sender);
+    } else {
+      this._rejectFailedTransactionsFrom.delete(sender);
+    }
+
+    return true;
+  }
+}
@@ -0,0 +1,87 @@
+import type { EIP1193Provider } from "../../../../types";
+
+import * as t from "io-ts";
+
+import { validateParams } from "../../../core/jsonrpc/types/input/validation";
+import {
+  InvalidInputError,
+  MethodNotFoundError,
+} from "../../../core/providers/errors";
+import { checksumAddressesInResult } from "../utils/checksumAddresses";
+
+import { ProviderModule } from "./module";
+
+/* eslint-disable @nomicfoundation/hardhat-internal-rules/only-hardhat-error */
+
+export class RemoteTransactionLookupModule implements ProviderModule {
+  // If true, unknown transactions are looked up in the forked network
+  private _remoteTransactionLookup = false;
+
+  constructor(
+    private readonly _getForkProvider: () => EIP1193Provider | undefined,
+    private readonly _checksumAddresses: boolean
+  ) {}
+
+  public handles(method: string): boolean {
+    return method === "hardhat_setRemoteTransactionLookup";
+  }
+
+  public async processRequest(method: string, params: any[]): Promise<unknown> {
+    switch (method) {
+      case "hardhat_setRemoteTransactionLookup":
+        return this._setRemoteTransactionLookupAction(
+          ...this._setRemoteTransactionLookupParams(params)
+        );
+    }
+
+    throw new MethodNotFoundError(`Method ${method} not found`);
+  }
+
+  /**
+   * Looks up a transaction or receipt that isn't known locally in the forked
+   * network, adding a `remote` field to it. It returns null if the lookups
+   * are disabled.
+   */
+  public async getRemoteTransaction(
+    method: string,
+    params: any[]
+  ): Promise<unknown> {
+    if (!this._remoteTransactionLookup) {
+      return null;
+    }
+
+    const forkProvider = this._getForkProvider();
+    if (forkProvider === undefined) {
+      return null;
+    }
+
+    const remoteResult: any = await forkProvider.request({ method, params });
+    if (remoteResult === null) {
+      return null;
+    }
+
+    const result = { ...remoteResult, remote: true };
+
+    return this._checksumAddresses
+      ? checksumAddressesInResult(method, result)
+      : result;
+  }
+
+  // hardhat_setRemoteTransactionLookup
+
+  private _setRemoteTransactionLookupParams(params: any[]): [boolean] {
+    return validateParams(params, t.boolean);
+  }
+
+  private _setRemoteTransactionLookupAction(enabled: boolean): boolean {
+    if (enabled && this._getForkProvider() === undefined) {
+      throw new InvalidInputError(
+        "Remote transaction lookups can only be enabled when forking"
+      );
+    }
+
+    this._remoteTransactionLookup = enabled;
+
+    return true;
+  }
+}
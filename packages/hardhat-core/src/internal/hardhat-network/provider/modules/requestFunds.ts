@@ -0,0 +1,82 @@
+import type { EIP1193Provider } from "../../../../types";
+
+import {
+  bufferToRpcData,
+  numberToRpcQuantity,
+  rpcAddress,
+  rpcQuantity,
+} from "../../../core/jsonrpc/types/base-types";
+import { validateParams } from "../../../core/jsonrpc/types/input/validation";
+import { MethodNotFoundError } from "../../../core/providers/errors";
+import { Mutex } from "../../../vendor/await-semaphore";
+import { FaucetRateLimiter } from "../utils/faucetRateLimiter";
+
+import { ProviderModule } from "./module";
+
+/* eslint-disable @nomicfoundation/hardhat-internal-rules/only-hardhat-error */
+
+export class RequestFundsModule implements ProviderModule {
+  // Serializes the hardhat_requestFunds requests
+  private readonly _faucetMutex = new Mutex();
+
+  constructor(
+    private readonly _provider: EIP1193Provider,
+    private readonly _faucetRateLimiter: FaucetRateLimiter | undefined
+  ) {}
+
+  public handles(method: string): boolean {
+    return method === "hardhat_requestFunds";
+  }
+
+  public async processRequest(method: string, params: any[]): Promise<unknown> {
+    switch (method) {
+      case "hardhat_requestFunds":
+        return this._requestFundsAction(...this._requestFundsParams(params));
+    }
+
+    throw new MethodNotFoundError(`Method ${method} not found`);
+  }
+
+  public reset() {
+    this._faucetRateLimiter?.clear();
+  }
+
+  // hardhat_requestFunds
+
+  private _requestFundsParams(params: any[]): [Buffer, bigint] {
+    return validateParams(params, rpcAddress, rpcQuantity);
+  }
+
+  private async _requestFundsAction(
+    address: Buffer,
+    amount: bigint
+  ): Promise<boolean> {
+    const rpcAddressValue = bufferToRpcData(address);
+
+    // Requests are handled one at a time, so that concurrent ones can't
+    // exceed the limit or overwrite each other's balance
+    return this._faucetMutex.use(async () => {
+      this._faucetRateLimiter?.assertCanRequest(rpcAddressValue, amount);
+
+      // hardhat_setBalance changes the latest state, which the pending
+      // transactions are applied on top of
+      const balance = await this._provider.request({
+        method: "eth_getBalance",
+        params: [rpcAddressValue, "latest"],
+      });
+
+      await this._provider.request({
+        method: "hardhat_setBalance",
+        params: [
+          rpcAddressValue,
+          numberToRpcQuantity(BigInt(balance as string) + amount),
+        ],
+      });
+
+      // Only the funds that were sent count towards the limit
+      this._faucetRateLimiter?.registerRequest(rpcAddressValue, amount);
+
+      return true;
+    });
+  }
+}
@@ -0,0 +1,57 @@
+import type EthereumjsUtilT from "@nomicfoundation/ethereumjs-util";
+
+import * as t from "io-ts";
+
+import { rpcAddress, rpcData } from "../../../core/jsonrpc/types/base-types";
+import { validateParams } from "../../../core/jsonrpc/types/input/validation";
+import { MethodNotFoundError } from "../../../core/providers/errors";
+import {
+  recoverMessageSigner,
+  verifyTypedDataSignature,
+} from "../utils/signatures";
+
+import { ProviderModule } from "./module";
+
+/* eslint-disable @nomicfoundation/hardhat-internal-rules/only-hardhat-error */
+
+export class SignaturesModule implements ProviderModule {
+  constructor(private readonly _checksumAddresses: boolean) {}
+
+  public handles(method: string): boolean {
+    return (
+      method === "hardhat_recoverSigner" || method === "hardhat_verifyTypedData"
+    );
+  }
+
+  public async processRequest(method: string, params: any[]): Promise<unknown> {
+    switch (method) {
+      case "hardhat_recoverSigner":
+        return this._recoverSignerAction(...this._recoverSignerParams(params));
+      case "hardhat_verifyTypedData":
+        return verifyTypedDataSignature(...this._verifyTypedDataParams(params));
+    }
+
+    throw new MethodNotFoundError(`Method ${method} not found`);
+  }
+
+  // hardhat_recoverSigner
+
+  private _recoverSignerParams(params: any[]): [Buffer, Buffer] {
+    return validateParams(params, rpcData, rpcData);
+  }
+
+  private _recoverSignerAction(message: Buffer, signature: Buffer): string {
+    const { toChecksumAddress } =
+      require("@nomicfoundation/ethereumjs-util") as typeof EthereumjsUtilT;
+
+    const signer = recoverMessageSigner(message, signature);
+
+    return this._checksumAddresses ? toChecksumAddress(signer) : signer;
+  }
+
+  // hardhat_verifyTypedData
+
+  private _verifyTypedDataParams(params: any[]): [Buffer, unknown, Buffer] {
+    return validateParams(params, rpcAddress, t.unknown, rpcData);
+  }
+}
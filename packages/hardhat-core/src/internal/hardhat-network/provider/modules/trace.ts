@@ -0,0 +1,129 @@
+import type { Common } from "@nomicfoundation/ethereumjs-common";
+import type {
+  EIP1193Provider,
+  HardhatNetworkChainsConfig,
+} from "../../../../types";
+
+import {
+  bufferToRpcData,
+  numberToRpcQuantity,
+  rpcHash,
+} from "../../../core/jsonrpc/types/base-types";
+import {
+  RpcOldBlockTag,
+  rpcOldBlockTag,
+} from "../../../core/jsonrpc/types/input/blockTag";
+import { validateParams } from "../../../core/jsonrpc/types/input/validation";
+import {
+  InvalidArgumentsError,
+  MethodNotFoundError,
+} from "../../../core/providers/errors";
+import { HardforkName } from "../../../util/hardforks";
+import { getBlockCommon, traceBlock } from "../utils/blocks";
+import {
+  FLAT_CALL_TRACER,
+  FlatTrace,
+  getBlockRewardTrace,
+} from "../utils/flatCallTracer";
+import { getEthereumJsHardfork } from "../utils/makeCommon";
+
+import { ProviderModule } from "./module";
+
+/* eslint-disable @nomicfoundation/hardhat-internal-rules/only-hardhat-error */
+
+export class TraceModule implements ProviderModule {
+  constructor(
+    private readonly _provider: EIP1193Provider,
+    private readonly _common: Common,
+    private readonly _chains: HardhatNetworkChainsConfig
+  ) {}
+
+  public handles(method: string): boolean {
+    return method === "trace_transaction" || method === "trace_block";
+  }
+
+  public async processRequest(method: string, params: any[]): Promise<unknown> {
+    switch (method) {
+      case "trace_transaction":
+        return this._transactionAction(...this._transactionParams(params));
+      case "trace_block":
+        return this._blockAction(...this._blockParams(params));
+    }
+
+    throw new MethodNotFoundError(`Method ${method} not found`);
+  }
+
+  // trace_transaction
+
+  private _transactionParams(params: any[]): [Buffer] {
+    return validateParams(params, rpcHash);
+  }
+
+  private async _transactionAction(hash: Buffer): Promise<FlatTrace[] | null> {
+    const txHash = bufferToRpcData(hash);
+    const tx: any = await this._provider.request({
+      method: "eth_getTransactionByHash",
+      params: [txHash],
+    });
+
+    // Pending transactions don't have traces yet
+    if (tx === null || tx.blockHash === null) {
+      return null;
+    }
+
+    return (await this._provider.request({
+      method: "debug_traceTransaction",
+      params: [txHash, { tracer: FLAT_CALL_TRACER }],
+    })) as FlatTrace[];
+  }
+
+  // trace_block
+
+  private _blockParams(params: any[]): [RpcOldBlockTag] {
+    return validateParams(params, rpcOldBlockTag);
+  }
+
+  private async _blockAction(
+    blockTag: RpcOldBlockTag
+  ): Promise<FlatTrace[] | null> {
+    if (blockTag === "pending") {
+      throw new InvalidArgumentsError(
+        "trace_block doesn't support the pending block"
+      );
+    }
+
+    const blockNumber =
+      typeof blockTag === "bigint" ? numberToRpcQuantity(blockTag) : blockTag;
+
+    const block: any = await this._provider.request({
+      method: "eth_getBlockByNumber",
+      params: [blockNumber, false],
+    });
+
+    if (block === null) {
+      return null;
+    }
+
+    const txTraces = await traceBlock(this._provider, block, blockNumber, {
+      tracer: FLAT_CALL_TRACER,
+    });
+
+    const traces = txTraces.flatMap(({ result }) => result as FlatTrace[]);
+
+    // Blocks after the merge don't have rewards. Uncle rewards aren't
+    // included.
+    const common = await getBlockCommon(
+      this._provider,
+      this._common,
+      this._chains,
+      BigInt(block.number)
+    );
+    if (!common.gteHardfork(getEthereumJsHardfork(HardforkName.MERGE))) {
+      traces.push(
+        getBlockRewardTrace(block, common.param("pow", "minerReward"))
+      );
+    }
+
+    return traces;
+  }
+}
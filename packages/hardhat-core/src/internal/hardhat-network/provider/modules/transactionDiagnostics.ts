@@ -0,0 +1,58 @@
+import type { EIP1193Provider } from "../../../../types";
+
+import {
+  bufferToRpcData,
+  rpcHash,
+} from "../../../core/jsonrpc/types/base-types";
+import { validateParams } from "../../../core/jsonrpc/types/input/validation";
+import { MethodNotFoundError } from "../../../core/providers/errors";
+import { RpcDebugTraceOutput } from "../output";
+import {
+  CallDiagnostics,
+  computeCallDiagnostics,
+} from "../utils/callDiagnostics";
+
+import { ProviderModule } from "./module";
+
+/* eslint-disable @nomicfoundation/hardhat-internal-rules/only-hardhat-error */
+
+export class TransactionDiagnosticsModule implements ProviderModule {
+  constructor(private readonly _provider: EIP1193Provider) {}
+
+  public handles(method: string): boolean {
+    return method === "hardhat_getTransactionDiagnostics";
+  }
+
+  public async processRequest(method: string, params: any[]): Promise<unknown> {
+    switch (method) {
+      case "hardhat_getTransactionDiagnostics":
+        return this._getTransactionDiagnosticsAction(
+          ...this._getTransactionDiagnosticsParams(params)
+        );
+    }
+
+    throw new MethodNotFoundError(`Method ${method} not found`);
+  }
+
+  // hardhat_getTransactionDiagnostics
+
+  private _getTransactionDiagnosticsParams(params: any[]): [Buffer] {
+    return validateParams(params, rpcHash);
+  }
+
+  private async _getTransactionDiagnosticsAction(
+    hash: Buffer
+  ): Promise<CallDiagnostics> {
+    // The memory size of each step is part of the struct logs, so the memory
+    // itself isn't recorded
+    const trace = (await this._provider.request({
+      method: "debug_traceTransaction",
+      params: [
+        bufferToRpcData(hash),
+        { disableStack: true, disableMemory: true, disableStorage: true },
+      ],
+    })) as RpcDebugTraceOutput;
+
+    return computeCallDiagnostics(trace);
+  }
+}
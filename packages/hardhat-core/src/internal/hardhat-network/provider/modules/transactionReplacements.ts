@@ -0,0 +1,167 @@
+import type { EIP1193Provider } from "../../../../types";
+
+import debug from "debug";
+import * as t from "io-ts";
+
+import {
+  bufferToRpcData,
+  rpcHash,
+  rpcQuantityToBigInt,
+} from "../../../core/jsonrpc/types/base-types";
+import { validateParams } from "../../../core/jsonrpc/types/input/validation";
+import {
+  InvalidInputError,
+  MethodNotFoundError,
+} from "../../../core/providers/errors";
+import { TransactionReplacements } from "../utils/transactionReplacements";
+
+import { ProviderModule } from "./module";
+
+/* eslint-disable @nomicfoundation/hardhat-internal-rules/only-hardhat-error */
+
+const log = debug("hardhat:core:hardhat-network:provider");
+
+export class TransactionReplacementsModule implements ProviderModule {
+  private readonly _transactionReplacements = new TransactionReplacements();
+
+  // If true, the receipts of replaced transactions throw instead of being
+  // null
+  private _replacedTransactionErrors = false;
+
+  // Cached value of hardhat_getAutomine, unknown after a reset
+  private _automine?: boolean;
+
+  constructor(private readonly _provider: EIP1193Provider) {}
+
+  public handles(method: string): boolean {
+    return (
+      method === "hardhat_getTransactionReplacement" ||
+      method === "hardhat_setReplacedTransactionErrors"
+    );
+  }
+
+  public async processRequest(method: string, params: any[]): Promise<unknown> {
+    switch (method) {
+      case "hardhat_getTransactionReplacement":
+        return this._getTransactionReplacementAction(
+          ...this._getTransactionReplacementParams(params)
+        );
+      case "hardhat_setReplacedTransactionErrors":
+        return this._setReplacedTransactionErrorsAction(
+          ...this._setReplacedTransactionErrorsParams(params)
+        );
+    }
+
+    throw new MethodNotFoundError(`Method ${method} not found`);
+  }
+
+  public reset() {
+    this._transactionReplacements.clear();
+    this._replacedTransactionErrors = false;
+    this._automine = undefined;
+  }
+
+  /**
+   * Records a transaction that was added to the mempool to detect its
+   * replacement. Failing to do it doesn't make the transaction fail, as it was
+   * already sent.
+   */
+  public async recordSentTransaction(txHash: string) {
+    try {
+      // With automine, transactions are mined as soon as they are sent, so
+      // they can't be replaced
+      if (await this._isAutomineEnabled()) {
+        return;
+      }
+
+      const tx: any = await this._provider.request({
+        method: "eth_getTransactionByHash",
+        params: [txHash],
+      });
+
+      // Mined transactions can't be replaced anymore
+      if (tx === null || tx.blockNumber !== null) {
+        return;
+      }
+
+      const replacedTxHash = this._transactionReplacements.addTransaction(
+        tx.from,
+        rpcQuantityToBigInt(tx.nonce),
+        txHash
+      );
+
+      if (replacedTxHash !== undefined) {
+        log(`Transaction ${replacedTxHash} replaced by ${txHash}`);
+      }
+    } catch (error) {
+      log("Failed to record the sent transaction %s: %O", txHash, error);
+    }
+  }
+
+  public removeTransaction(txHash: string) {
+    this._transactionReplacements.removeTransaction(txHash);
+  }
+
+  public clearSentTransactions() {
+    this._transactionReplacements.clearSentTransactions();
+  }
+
+  public setAutomine(enabled: boolean) {
+    this._automine = enabled;
+  }
+
+  /**
+   * Throws if the replaced transaction errors are enabled and the transaction
+   * whose receipt wasn't found was replaced.
+   */
+  public throwIfReplaced(txHash: string) {
+    if (!this._replacedTransactionErrors) {
+      return;
+    }
+
+    const replacement = this._transactionReplacements.getReplacement(txHash);
+    if (replacement === undefined) {
+      return;
+    }
+
+    const error = new InvalidInputError(
+      `Transaction ${txHash} was replaced by transaction ${replacement}`
+    );
+    error.data = { replacedBy: replacement };
+
+    throw error;
+  }
+
+  // hardhat_getTransactionReplacement
+
+  private _getTransactionReplacementParams(params: any[]): [Buffer] {
+    return validateParams(params, rpcHash);
+  }
+
+  private _getTransactionReplacementAction(txHash: Buffer): string | null {
+    return (
+      this._transactionReplacements.getReplacement(bufferToRpcData(txHash)) ??
+      null
+    );
+  }
+
+  // hardhat_setReplacedTransactionErrors
+
+  private _setReplacedTransactionErrorsParams(params: any[]): [boolean] {
+    return validateParams(params, t.boolean);
+  }
+
+  private _setReplacedTransactionErrorsAction(enabled: boolean): boolean {
+    this._replacedTransactionErrors = enabled;
+
+    return true;
+  }
+
+  private async _isAutomineEnabled(): Promise<boolean> {
+    this._automine ??= (await this._provider.request({
+      method: "hardhat_getAutomine",
+    })) as boolean;
+
+    return this._automine;
+  }
+}
@@ -0,0 +1,44 @@
+import * as t from "io-ts";
+
+import {
+  bufferToRpcData,
+  rpcHash,
+} from "../../../core/jsonrpc/types/base-types";
+import { validateParams } from "../../../core/jsonrpc/types/input/validation";
+import { MethodNotFoundError } from "../../../core/providers/errors";
+import { TransactionTags } from "../utils/transactionTags";
+
+import { ProviderModule } from "./module";
+
+/* eslint-disable @nomicfoundation/hardhat-internal-rules/only-hardhat-error */
+
+export class TransactionTagsModule implements ProviderModule {
+  constructor(private readonly _transactionTags: TransactionTags) {}
+
+  public handles(method: string): boolean {
+    return method === "hardhat_setTransactionTag";
+  }
+
+  public async processRequest(method: string, params: any[]): Promise<unknown> {
+    switch (method) {
+      case "hardhat_setTransactionTag":
+        return this._setTransactionTagAction(
+          ...this._setTransactionTagParams(params)
+        );
+    }
+
+    throw new MethodNotFoundError(`Method ${method} not found`);
+  }
+
+  // hardhat_setTransactionTag
+
+  private _setTransactionTagParams(params: any[]): [Buffer, unknown] {
+    return validateParams(params, rpcHash, t.unknown);
+  }
+
+  private _setTransactionTagAction(txHash: Buffer, tag: unknown): boolean {
+    this._transactionTags.setTag(bufferToRpcData(txHash), tag);
+
+    return true;
+  }
+}
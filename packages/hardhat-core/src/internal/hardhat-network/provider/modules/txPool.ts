@@ -0,0 +1,82 @@
+import type { EIP1193Provider } from "../../../../types";
+
+import { rpcQuantityToBigInt } from "../../../core/jsonrpc/types/base-types";
+import { validateParams } from "../../../core/jsonrpc/types/input/validation";
+import { MethodNotFoundError } from "../../../core/providers/errors";
+import {
+  getTxPoolContent,
+  getTxPoolStatus,
+  inspectTxPoolContent,
+  TxPoolContent,
+  TxPoolStatus,
+} from "../utils/txPool";
+
+import { ProviderModule } from "./module";
+
+/* eslint-disable @nomicfoundation/hardhat-internal-rules/only-hardhat-error */
+
+export class TxPoolModule implements ProviderModule {
+  constructor(private readonly _provider: EIP1193Provider) {}
+
+  public handles(method: string): boolean {
+    return (
+      method === "txpool_content" ||
+      method === "txpool_inspect" ||
+      method === "txpool_status"
+    );
+  }
+
+  public async processRequest(method: string, params: any[]): Promise<unknown> {
+    switch (method) {
+      case "txpool_content":
+        return this._contentAction(...this._contentParams(params));
+      case "txpool_inspect":
+        return this._inspectAction(...this._inspectParams(params));
+      case "txpool_status":
+        return this._statusAction(...this._statusParams(params));
+    }
+
+    throw new MethodNotFoundError(`Method ${method} not found`);
+  }
+
+  // txpool_content
+
+  private _contentParams(params: any[]): [] {
+    return validateParams(params);
+  }
+
+  private async _contentAction(): Promise<TxPoolContent<any>> {
+    const transactions = (await this._provider.request({
+      method: "eth_pendingTransactions",
+    })) as any[];
+
+    return getTxPoolContent(transactions, async (sender) =>
+      rpcQuantityToBigInt(
+        (await this._provider.request({
+          method: "eth_getTransactionCount",
+          params: [sender, "latest"],
+        })) as string
+      )
+    );
+  }
+
+  // txpool_inspect
+
+  private _inspectParams(params: any[]): [] {
+    return validateParams(params);
+  }
+
+  private async _inspectAction(): Promise<TxPoolContent<string>> {
+    return inspectTxPoolContent(await this._contentAction());
+  }
+
+  // txpool_status
+
+  private _statusParams(params: any[]): [] {
+    return validateParams(params);
+  }
+
+  private async _statusAction(): Promise<TxPoolStatus> {
+    return getTxPoolStatus(await this._contentAction());
+  }
+}
@@ -0,0 +1,54 @@
+import type { Common } from "@nomicfoundation/ethereumjs-common";
+import type { EIP1193Provider } from "../../../../types";
+
+import { rpcData } from "../../../core/jsonrpc/types/base-types";
+import { validateParams } from "../../../core/jsonrpc/types/input/validation";
+import { MethodNotFoundError } from "../../../core/providers/errors";
+import {
+  RawTransactionVerdict,
+  validateRawTransaction,
+} from "../utils/validateRawTransaction";
+
+import { ProviderModule } from "./module";
+
+/* eslint-disable @nomicfoundation/hardhat-internal-rules/only-hardhat-error */
+
+export class ValidateRawTransactionModule implements ProviderModule {
+  constructor(
+    private readonly _provider: EIP1193Provider,
+    private readonly _common: Common,
+    private readonly _minGasPrice: bigint
+  ) {}
+
+  public handles(method: string): boolean {
+    return method === "hardhat_validateRawTransaction";
+  }
+
+  public async processRequest(method: string, params: any[]): Promise<unknown> {
+    switch (method) {
+      case "hardhat_validateRawTransaction":
+        return this._validateRawTransactionAction(
+          ...this._validateRawTransactionParams(params)
+        );
+    }
+
+    throw new MethodNotFoundError(`Method ${method} not found`);
+  }
+
+  // hardhat_validateRawTransaction
+
+  private _validateRawTransactionParams(params: any[]): [Buffer] {
+    return validateParams(params, rpcData);
+  }
+
+  private async _validateRawTransactionAction(
+    rawTransaction: Buffer
+  ): Promise<RawTransactionVerdict> {
+    return validateRawTransaction(
+      this._provider,
+      this._common,
+      this._minGasPrice,
+      rawTransaction
+    );
+  }
+}
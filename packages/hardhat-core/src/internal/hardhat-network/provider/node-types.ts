@@ -1,5 +1,9 @@
 import { HARDHAT_MEMPOOL_SUPPORTED_ORDERS } from "../../constants";
-import { BuildInfo, HardhatNetworkChainsConfig } from "../../../types";
+import {
+  BuildInfo,
+  HardhatNetworkChainsConfig,
+  HardhatNetworkForkingPrefetchConfig,
+} from "../../../types";
 
 export type NodeConfig = LocalNodeConfig | ForkedNodeConfig;
 
@@ -28,6 +32,7 @@ export interface ForkConfig {
   jsonRpcUrl: string;
   blockNumber?: number;
   httpHeaders?: { [name: string]: string };
+  prefetch?: HardhatNetworkForkingPrefetchConfig[];
 }
 
 export interface ForkedNodeConfig extends CommonConfig {
@@ -6,6 +6,8 @@ import type {
   EIP1193Provider,
   EthSubscription,
   HardhatNetworkChainsConfig,
+  HardhatNetworkForkingPrefetchConfig,
+  HardhatNetworkHDAccountsConfig,
   RequestArguments,
 } from "../../../types";
 
@@ -19,6 +21,7 @@ import type {
   TracingMessage,
   TracingStep,
 } from "@nomicfoundation/edr";
+import type EthereumjsUtilT from "@nomicfoundation/ethereumjs-util";
 import { Common } from "@nomicfoundation/ethereumjs-common";
 import chalk from "chalk";
 import debug from "debug";
@@ -29,9 +32,14 @@ import semver from "semver";
 
 import { requireNapiRsModule } from "../../../common/napi-rs";
 import {
+  HARDHAT_NETWORK_NAME,
   HARDHAT_NETWORK_RESET_EVENT,
   HARDHAT_NETWORK_REVERT_SNAPSHOT_EVENT,
 } from "../../constants";
+import {
+  bufferToRpcData,
+  numberToRpcQuantity,
+} from "../../core/jsonrpc/types/base-types";
 import {
   rpcCompilerInput,
   rpcCompilerOutput,
@@ -42,7 +50,7 @@ import {
   InvalidInputError,
   ProviderError,
 } from "../../core/providers/errors";
-import { isErrorResponse } from "../../core/providers/http";
+import { HttpProvider, isErrorResponse } from "../../core/providers/http";
 import { getHardforkName } from "../../util/hardforks";
 import { createModelsAndDecodeBytecodes } from "../stack-traces/compiler-to-model";
 import { ConsoleLogger } from "../stack-traces/consoleLogger";
@@ -75,8 +83,61 @@ import {
   ethereumjsMempoolOrderToEdrMineOrdering,
   ethereumsjsHardforkToEdrSpecId,
 } from "./utils/convertToEdr";
+import { CALL_TRACER, computeCallFrames, TracedCall } from "./utils/callTracer";
+import {
+  checksumAddressesInResult,
+  checksumAddressFields,
+} from "./utils/checksumAddresses";
+import { FaucetRateLimit, FaucetRateLimiter } from "./utils/faucetRateLimiter";
+import {
+  FLAT_CALL_TRACER,
+  FlatTraceContext,
+  flattenCallFrames,
+  getFlatTraceContext,
+} from "./utils/flatCallTracer";
+import {
+  computeFourByteStatistics,
+  FOUR_BYTE_TRACER,
+} from "./utils/fourByteTracer";
+import { FullTransactionFilters } from "./utils/fullTransactionFilters";
+import {
+  computeGasProfile,
+  GAS_PROFILE_TRACER,
+} from "./utils/gasProfileTracer";
+import { PercentileGasPriceOracleConfig } from "./utils/gasPriceOracle";
 import { makeCommon } from "./utils/makeCommon";
+import {
+  computeOpcodeStatistics,
+  OPCODE_STATS_TRACER,
+} from "./utils/opcodeStatistics";
+import { prefetchForkedState } from "./utils/prefetchForkedState";
+import {
+  extractTraceExportOption,
+  writeTraceToFile,
+} from "./utils/traceExport";
+import { extractTracer } from "./utils/tracingConfig";
+import { TransactionTags } from "./utils/transactionTags";
+import { BlockReceiptsModule } from "./modules/blockReceipts";
+import { DebugTraceBlockModule } from "./modules/debugTraceBlock";
+import { DeriveAccountModule } from "./modules/deriveAccount";
+import { DiffCallsModule } from "./modules/diffCalls";
+import { DisassembleModule } from "./modules/disassemble";
+import { GasPriceOracleModule } from "./modules/gasPriceOracle";
 import { LoggerConfig, printLine, replaceLastLine } from "./modules/logger";
+import { LogStatisticsModule } from "./modules/logStatistics";
+import { MulticallModule } from "./modules/multicall";
+import { ProviderModule } from "./modules/module";
+import { RawDataModule } from "./modules/rawData";
+import { RejectFailedTransactionsModule } from "./modules/rejectFailedTransactions";
+import { RemoteTransactionLookupModule } from "./modules/remoteTransactionLookup";
+import { RequestFundsModule } from "./modules/requestFunds";
+import { SignaturesModule } from "./modules/signatures";
+import { TraceModule } from "./modules/trace";
+import { TransactionDiagnosticsModule } from "./modules/transactionDiagnostics";
+import { TransactionReplacementsModule } from "./modules/transactionReplacements";
+import { TransactionTagsModule } from "./modules/transactionTags";
+import { TxPoolModule } from "./modules/txPool";
+import { ValidateRawTransactionModule } from "./modules/validateRawTransaction";
 import { MinimalEthereumJsVm, getMinimalEthereumJsVm } from "./vm/minimal-vm";
 
 const log = debug("hardhat:core:hardhat-network:provider");
@@ -123,6 +184,11 @@ interface HardhatNetworkProviderConfig {
   forkConfig?: ForkConfig;
   forkCachePath?: string;
   enableTransientStorage: boolean;
+  checksumAddresses?: boolean;
+  faucetRateLimit?: FaucetRateLimit;
+  // Only present if the accounts are derived from a mnemonic
+  hdAccounts?: HardhatNetworkHDAccountsConfig;
+  gasPriceOracle?: PercentileGasPriceOracleConfig;
 }
 
 export function getNodeConfig(
@@ -173,9 +239,31 @@ export class EdrProviderWrapper
 {
   private _failedStackTraces = 0;
 
+  private readonly _transactionTags = new TransactionTags();
+  private readonly _fullTransactionFilters = new FullTransactionFilters();
+  // Notifications with full transactions are emitted in order, after fetching
+  // their transactions
+  private _fullTransactionNotifications: Promise<void> = Promise.resolve();
+
+  // Created the first time that the forked network has to be queried directly
+  private _forkProvider?: HttpProvider;
+
   // temporarily added to make smock work with HH+EDR
   private _callOverrideCallback?: CallOverrideCallback;
 
+  // Keeps track of the replaced transactions, after the requests that send,
+  // drop or revert them
+  private readonly _transactionReplacementsModule: TransactionReplacementsModule;
+
+  // Looks up the transactions and receipts that aren't known locally
+  private readonly _remoteTransactionLookupModule: RemoteTransactionLookupModule;
+
+  // Rejects the failing transactions of some senders before they are sent
+  private readonly _rejectFailedTransactionsModule: RejectFailedTransactionsModule;
+
+  // The methods that are handled here instead of by EDR
+  private readonly _modules: ProviderModule[];
+
   private constructor(
     private readonly _provider: EdrProviderT,
     // we add this for backwards-compatibility with plugins like solidity-coverage
@@ -187,10 +275,56 @@ export class EdrProviderWrapper
     private readonly _rawTraceCallbacks: RawTraceCallbacks,
     // The common configuration for EthereumJS VM is not used by EDR, but tests expect it as part of the provider.
     private readonly _common: Common,
+    // Whether addresses in responses and subscription events are returned
+    // in their EIP-55 checksummed form instead of lowercase
+    private readonly _checksumAddresses: boolean,
+    faucetRateLimiter: FaucetRateLimiter | undefined,
+    private _forkConfig: ForkConfig | undefined,
+    hdAccounts: HardhatNetworkHDAccountsConfig | undefined,
+    gasPriceOracleConfig: PercentileGasPriceOracleConfig | undefined,
+    chains: HardhatNetworkChainsConfig,
+    minGasPrice: bigint,
     tracingConfig?: TracingConfig
   ) {
     super();
 
+    this._transactionReplacementsModule = new TransactionReplacementsModule(
+      this
+    );
+
+    this._remoteTransactionLookupModule = new RemoteTransactionLookupModule(
+      () => this._getForkProvider(),
+      this._checksumAddresses
+    );
+
+    this._rejectFailedTransactionsModule = new RejectFailedTransactionsModule(
+      this
+    );
+
+    this._modules = [
+      new TransactionTagsModule(this._transactionTags),
+      new GasPriceOracleModule(this, gasPriceOracleConfig, () =>
+        this._getForkProvider()
+      ),
+      new SignaturesModule(this._checksumAddresses),
+      new DeriveAccountModule(hdAccounts, this._checksumAddresses),
+      this._transactionReplacementsModule,
+      this._remoteTransactionLookupModule,
+      this._rejectFailedTransactionsModule,
+      new ValidateRawTransactionModule(this, this._common, minGasPrice),
+      new LogStatisticsModule(this),
+      new TransactionDiagnosticsModule(this),
+      new MulticallModule(this),
+      new DiffCallsModule(this),
+      new RequestFundsModule(this, faucetRateLimiter),
+      new BlockReceiptsModule(this),
+      new TxPoolModule(this),
+      new DisassembleModule(this, this._common, chains),
+      new RawDataModule(this),
+      new DebugTraceBlockModule(this),
+      new TraceModule(this, this._common, chains),
+    ];
+
     if (tracingConfig !== undefined) {
       initializeVmTraceDecoder(this._vmTraceDecoder, tracingConfig);
     }
@@ -327,6 +461,15 @@ export class EdrProviderWrapper
       vmTraceDecoder,
       rawTraceCallbacks,
       common,
+      config.checksumAddresses ?? false,
+      config.faucetRateLimit !== undefined
+        ? new FaucetRateLimiter(config.faucetRateLimit)
+        : undefined,
+      config.forkConfig,
+      config.hdAccounts,
+      config.gasPriceOracle,
+      config.chains,
+      config.minGasPrice,
       tracingConfig
     );
 
@@ -336,6 +479,10 @@ export class EdrProviderWrapper
       wrapper._ethEventListener.bind(wrapper)
     );
 
+    if (config.forkConfig?.prefetch !== undefined) {
+      await wrapper._prefetchForkedState(config.forkConfig.prefetch);
+    }
+
     return wrapper;
   }
 
@@ -346,7 +493,7 @@ export class EdrProviderWrapper
       );
     }
 
-    const params = args.params ?? [];
+    let params = args.params ?? [];
 
     if (args.method === "hardhat_addCompilationResult") {
       return this._addCompilationResultAction(
@@ -358,15 +505,94 @@ export class EdrProviderWrapper
       );
     }
 
+    const providerModule = this._modules.find((m) => m.handles(args.method));
+    if (providerModule !== undefined) {
+      return providerModule.processRequest(args.method, params);
+    }
+
+    let tag: unknown;
+    if (args.method === "eth_sendTransaction") {
+      [params, tag] = this._transactionTags.extractTag(params);
+      await this._rejectFailedTransactionsModule.rejectIfFailing(params);
+    }
+
+    let collectOpcodeStatistics: boolean;
+    [params, collectOpcodeStatistics] = extractTracer(
+      args.method,
+      params,
+      OPCODE_STATS_TRACER,
+      false
+    );
+
+    let collectCallFrames: boolean;
+    [params, collectCallFrames] = extractTracer(
+      args.method,
+      params,
+      CALL_TRACER,
+      true
+    );
+
+    let collectFlatCallFrames: boolean;
+    [params, collectFlatCallFrames] = extractTracer(
+      args.method,
+      params,
+      FLAT_CALL_TRACER,
+      true
+    );
+
+    let collectGasProfile: boolean;
+    [params, collectGasProfile] = extractTracer(
+      args.method,
+      params,
+      GAS_PROFILE_TRACER,
+      true
+    );
+
+    let collectFourByteStatistics: boolean;
+    [params, collectFourByteStatistics] = extractTracer(
+      args.method,
+      params,
+      FOUR_BYTE_TRACER,
+      true
+    );
+
+    let exportTraceToFile: boolean;
+    [params, exportTraceToFile] = extractTraceExportOption(args.method, params);
+
+    let fullTransactions: boolean;
+    [params, fullTransactions] = this._fullTransactionFilters.extractFlag(
+      args.method,
+      params
+    );
+
     const stringifiedArgs = JSON.stringify({
       method: args.method,
       params,
     });
 
-    const responseObject: Response = await this._provider.handleRequest(
-      stringifiedArgs
-    );
-    const response = JSON.parse(responseObject.json);
+    // Notifications of pending transactions wait until the transaction is
+    // tagged, as they can be emitted before its hash is returned
+    const finishTagging =
+      tag !== undefined ? this._transactionTags.startTagging() : undefined;
+
+    let responseObject: Response;
+    let response: any;
+    try {
+      responseObject = await this._provider.handleRequest(stringifiedArgs);
+      response = JSON.parse(responseObject.json);
+
+      if (tag !== undefined) {
+        const txHash = isErrorResponse(response)
+          ? response.error.data?.transactionHash
+          : response.result;
+
+        if (typeof txHash === "string") {
+          this._transactionTags.setTag(txHash, tag);
+        }
+      }
+    } finally {
+      finishTagging?.();
+    }
 
     const needsTraces =
       this._node._vm.evm.events.eventNames().length > 0 ||
@@ -464,12 +690,55 @@ export class EdrProviderWrapper
       throw error;
     }
 
+    if (fullTransactions) {
+      this._fullTransactionFilters.add(response.result);
+    }
+
     if (args.method === "hardhat_reset") {
+      this._transactionTags.clear();
+      this._fullTransactionFilters.clear();
+
+      for (const providerModule of this._modules) {
+        providerModule.reset?.();
+      }
+
+      const forking = params[0]?.forking;
+      this._forkProvider = undefined;
+      this._forkConfig =
+        forking?.jsonRpcUrl !== undefined
+          ? {
+              jsonRpcUrl: forking.jsonRpcUrl,
+              httpHeaders: forking.httpHeaders,
+            }
+          : undefined;
+
       this.emit(HARDHAT_NETWORK_RESET_EVENT);
     } else if (args.method === "evm_revert") {
+      this._transactionReplacementsModule.clearSentTransactions();
       this.emit(HARDHAT_NETWORK_REVERT_SNAPSHOT_EVENT);
+    } else if (args.method === "evm_setAutomine") {
+      this._transactionReplacementsModule.setAutomine(params[0] === true);
+    } else if (
+      args.method === "eth_sendTransaction" ||
+      args.method === "eth_sendRawTransaction"
+    ) {
+      await this._transactionReplacementsModule.recordSentTransaction(
+        response.result
+      );
+    } else if (
+      args.method === "hardhat_dropTransaction" &&
+      response.result === true
+    ) {
+      this._transactionReplacementsModule.removeTransaction(params[0]);
+    } else if (
+      args.method === "eth_uninstallFilter" ||
+      args.method === "eth_unsubscribe"
+    ) {
+      this._fullTransactionFilters.remove(params[0]);
     }
 
+    let result: unknown;
+
     // Override EDR version string with Hardhat version string with EDR backend,
     // e.g. `HardhatNetwork/2.19.0/@nomicfoundation/edr/0.2.0-dev`
     if (args.method === "web3_clientVersion") {
@@ -478,10 +747,201 @@ export class EdrProviderWrapper
       args.method === "debug_traceTransaction" ||
       args.method === "debug_traceCall"
     ) {
-      return edrRpcDebugTraceToHardhat(response.result);
+      const trace = edrRpcDebugTraceToHardhat(response.result);
+
+      let traceResult: unknown = trace;
+      if (collectOpcodeStatistics) {
+        traceResult = computeOpcodeStatistics(trace);
+      } else if (collectFourByteStatistics) {
+        const tracedCall = await this._getTracedCall(args.method, params);
+        traceResult = computeFourByteStatistics(
+          trace,
+          tracedCall.type === "CALL" ? tracedCall.input : undefined
+        );
+      } else if (collectCallFrames) {
+        traceResult = computeCallFrames(
+          trace,
+          await this._getTracedCall(args.method, params)
+        );
+      } else if (collectFlatCallFrames) {
+        traceResult = flattenCallFrames(
+          computeCallFrames(
+            trace,
+            await this._getTracedCall(args.method, params)
+          ),
+          await this._getFlatTraceContext(args.method, params)
+        );
+      } else if (collectGasProfile) {
+        traceResult = computeGasProfile(
+          computeCallFrames(
+            trace,
+            await this._getTracedCall(args.method, params)
+          )
+        );
+      }
+
+      result = exportTraceToFile
+        ? { file: await writeTraceToFile(traceResult) }
+        : traceResult;
+    } else if (
+      args.method === "eth_getFilterChanges" &&
+      this._fullTransactionFilters.has(params[0])
+    ) {
+      result = await this._getPendingTransactions(response.result);
+    } else if (this._checksumAddresses) {
+      result = checksumAddressesInResult(args.method, response.result);
     } else {
-      return response.result;
+      result = response.result;
+    }
+
+    if (result === null && args.method === "eth_getTransactionReceipt") {
+      this._transactionReplacementsModule.throwIfReplaced(params[0]);
+    }
+
+    if (
+      result === null &&
+      (args.method === "eth_getTransactionByHash" ||
+        args.method === "eth_getTransactionReceipt")
+    ) {
+      result = await this._remoteTransactionLookupModule.getRemoteTransaction(
+        args.method,
+        params
+      );
+    }
+
+    return this._transactionTags.addTagsToResult(args.method, params, result);
+  }
+
+  /**
+   * Returns the block and position of the transaction traced by a
+   * `debug_traceTransaction` request. Calls don't have them.
+   */
+  private async _getFlatTraceContext(
+    method: string,
+    params: any[]
+  ): Promise<FlatTraceContext | undefined> {
+    if (method !== "debug_traceTransaction") {
+      return undefined;
+    }
+
+    const tx: any = await this.request({
+      method: "eth_getTransactionByHash",
+      params: [params[0]],
+    });
+
+    return getFlatTraceContext(tx);
+  }
+
+  /**
+   * Returns the call or transaction traced by a `debug_traceCall` or
+   * `debug_traceTransaction` request.
+   */
+  private async _getTracedCall(
+    method: string,
+    params: any[]
+  ): Promise<TracedCall> {
+    if (method === "debug_traceTransaction") {
+      const tx: any = await this.request({
+        method: "eth_getTransactionByHash",
+        params: [params[0]],
+      });
+
+      let to: string | undefined = tx.to ?? undefined;
+      if (to === undefined) {
+        const receipt: any = await this.request({
+          method: "eth_getTransactionReceipt",
+          params: [params[0]],
+        });
+
+        to = receipt.contractAddress ?? undefined;
+      }
+
+      return {
+        type: tx.to === null ? "CREATE" : "CALL",
+        from: tx.from,
+        to,
+        value: tx.value,
+        gas: tx.gas,
+        input: tx.input,
+      };
+    }
+
+    const call = params[0];
+
+    // Like eth_call, calls without a sender are sent from the first account
+    let from: string | undefined = call.from;
+    if (from === undefined) {
+      const accounts = (await this.request({
+        method: "eth_accounts",
+      })) as string[];
+
+      from = accounts[0] ?? `0x${"0".repeat(40)}`;
+    }
+
+    const tracedCall: TracedCall = {
+      type: call.to === undefined || call.to === null ? "CREATE" : "CALL",
+      from,
+      to: call.to ?? undefined,
+      value: call.value ?? numberToRpcQuantity(0),
+      gas: call.gas,
+      input: call.data ?? call.input ?? "0x",
+    };
+
+    // The address of a contract deployed by a call depends on the nonce of
+    // its sender in the block it runs on
+    if (tracedCall.type === "CREATE") {
+      const { generateAddress, toBuffer } =
+        require("@nomicfoundation/ethereumjs-util") as typeof EthereumjsUtilT;
+
+      const nonce = await this.request({
+        method: "eth_getTransactionCount",
+        params: [from, params[1] ?? "latest"],
+      });
+
+      tracedCall.to = bufferToRpcData(
+        generateAddress(toBuffer(from), toBuffer(nonce))
+      );
     }
+
+    return tracedCall;
+  }
+
+  private async _prefetchForkedState(
+    prefetch: HardhatNetworkForkingPrefetchConfig[]
+  ): Promise<void> {
+    const forkProvider = this._getForkProvider();
+    if (forkProvider === undefined) {
+      return;
+    }
+
+    const { forkedNetwork } = (await this.request({
+      method: "hardhat_metadata",
+    })) as { forkedNetwork?: { forkBlockNumber: number } };
+
+    if (forkedNetwork === undefined) {
+      return;
+    }
+
+    await prefetchForkedState(
+      forkProvider,
+      this,
+      numberToRpcQuantity(forkedNetwork.forkBlockNumber),
+      prefetch
+    );
+  }
+
+  private _getForkProvider(): HttpProvider | undefined {
+    if (this._forkConfig === undefined) {
+      return undefined;
+    }
+
+    this._forkProvider ??= new HttpProvider(
+      this._forkConfig.jsonRpcUrl,
+      HARDHAT_NETWORK_NAME,
+      this._forkConfig.httpHeaders
+    );
+
+    return this._forkProvider;
   }
 
   // temporarily added to make smock work with HH+EDR
@@ -502,12 +962,55 @@ export class EdrProviderWrapper
   private _ethEventListener(event: SubscriptionEvent) {
     const subscription = `0x${event.filterId.toString(16)}`;
     const results = Array.isArray(event.result) ? event.result : [event.result];
-    for (const result of results) {
+
+    if (this._fullTransactionFilters.has(subscription)) {
+      this._fullTransactionNotifications = this._fullTransactionNotifications
+        .then(() => this._emitPendingTransactions(subscription, results))
+        .catch((error) => {
+          log("Failed to emit full pending transactions: %O", error);
+        });
+
+      return;
+    }
+    for (let result of results) {
+      if (this._checksumAddresses) {
+        result = checksumAddressFields(result);
+      }
+
       this._emitLegacySubscriptionEvent(subscription, result);
       this._emitEip1193SubscriptionEvent(subscription, result);
     }
   }
 
+  private async _emitPendingTransactions(
+    subscription: string,
+    txHashes: string[]
+  ) {
+    await this._transactionTags.waitForPendingTags();
+
+    for (const tx of await this._getPendingTransactions(txHashes)) {
+      this._emitLegacySubscriptionEvent(subscription, tx);
+      this._emitEip1193SubscriptionEvent(subscription, tx);
+    }
+  }
+
+  /**
+   * Returns the transactions with the given hashes, skipping the ones that
+   * were dropped or replaced in the meantime.
+   */
+  private async _getPendingTransactions(txHashes: string[]): Promise<any[]> {
+    const txs = await Promise.all(
+      txHashes.map((txHash) =>
+        this.request({
+          method: "eth_getTransactionByHash",
+          params: [txHash],
+        })
+      )
+    );
+
+    return txs.filter((tx) => tx !== null);
+  }
+
   private _emitLegacySubscriptionEvent(subscription: string, result: any) {
     this.emit("notification", {
       subscription,
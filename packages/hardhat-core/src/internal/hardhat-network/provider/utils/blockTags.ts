@@ -0,0 +1,27 @@
+import {
+  bufferToRpcData,
+  numberToRpcQuantity,
+} from "../../../core/jsonrpc/types/base-types";
+import { RpcNewBlockTag } from "../../../core/jsonrpc/types/input/blockTag";
+
+/**
+ * Converts a decoded EIP-1898 block tag back to its JSON-RPC format.
+ */
+export function newBlockTagToRpc(blockTag: RpcNewBlockTag): unknown {
+  if (typeof blockTag === "bigint") {
+    return numberToRpcQuantity(blockTag);
+  }
+
+  if (typeof blockTag === "string") {
+    return blockTag;
+  }
+
+  if ("blockNumber" in blockTag) {
+    return { blockNumber: numberToRpcQuantity(blockTag.blockNumber) };
+  }
+
+  return {
+    blockHash: bufferToRpcData(blockTag.blockHash),
+    requireCanonical: blockTag.requireCanonical,
+  };
+}
@@ -0,0 +1,211 @@
+import type { Common } from "@nomicfoundation/ethereumjs-common";
+import type {
+  EIP1193Provider,
+  HardhatNetworkChainsConfig,
+} from "../../../../types";
+
+import * as t from "io-ts";
+
+import {
+  bufferToRpcData,
+  numberToRpcQuantity,
+  rpcHash,
+  rpcQuantityToBigInt,
+} from "../../../core/jsonrpc/types/base-types";
+import {
+  RpcNewBlockTag,
+  rpcNewBlockTag,
+} from "../../../core/jsonrpc/types/input/blockTag";
+import { RpcDebugTracingConfig } from "../../../core/jsonrpc/types/input/debugTraceTransaction";
+import {
+  InvalidArgumentsError,
+  InvalidInputError,
+} from "../../../core/providers/errors";
+import { selectHardfork } from "../../../util/hardforks";
+
+import { getEthereumJsHardfork } from "./makeCommon";
+
+/* eslint-disable @nomicfoundation/hardhat-internal-rules/only-hardhat-error */
+
+// The param of the methods that take a block hash or a block tag. Hashes are
+// checked first, as they are also valid quantities.
+export const rpcBlockHashOrTag = t.union([rpcHash, rpcNewBlockTag]);
+
+export type RpcBlockHashOrTag = t.TypeOf<typeof rpcBlockHashOrTag>;
+
+export interface BlockTransactionTrace {
+  txHash: string;
+  result: unknown;
+}
+
+/**
+ * Returns a block by its hash or block tag, for the methods that accept
+ * either of them. The pending block isn't supported, as it hasn't been
+ * mined yet.
+ */
+export async function getBlockByHashOrTag(
+  provider: EIP1193Provider,
+  method: string,
+  blockHashOrTag: RpcBlockHashOrTag,
+  includeTransactions: boolean
+): Promise<unknown> {
+  if (blockHashOrTag === "pending") {
+    throw new InvalidArgumentsError(
+      `${method} doesn't support the pending block`
+    );
+  }
+
+  if (Buffer.isBuffer(blockHashOrTag)) {
+    return provider.request({
+      method: "eth_getBlockByHash",
+      params: [bufferToRpcData(blockHashOrTag), includeTransactions],
+    });
+  }
+
+  if (typeof blockHashOrTag === "object" && "blockHash" in blockHashOrTag) {
+    return provider.request({
+      method: "eth_getBlockByHash",
+      params: [bufferToRpcData(blockHashOrTag.blockHash), includeTransactions],
+    });
+  }
+
+  const blockTag =
+    typeof blockHashOrTag === "object"
+      ? blockHashOrTag.blockNumber
+      : blockHashOrTag;
+
+  return provider.request({
+    method: "eth_getBlockByNumber",
+    params: [
+      typeof blockTag === "bigint" ? numberToRpcQuantity(blockTag) : blockTag,
+      includeTransactions,
+    ],
+  });
+}
+
+/**
+ * Returns the receipts of the transactions of a block, or null if the block
+ * doesn't exist.
+ */
+export async function getBlockReceipts(
+  provider: EIP1193Provider,
+  method: string,
+  blockHashOrTag: RpcBlockHashOrTag
+): Promise<unknown[] | null> {
+  const block = await getBlockByHashOrTag(
+    provider,
+    method,
+    blockHashOrTag,
+    false
+  );
+
+  if (block === null) {
+    return null;
+  }
+
+  return Promise.all(
+    ((block as any).transactions as string[]).map((txHash) =>
+      provider.request({
+        method: "eth_getTransactionReceipt",
+        params: [txHash],
+      })
+    )
+  );
+}
+
+/**
+ * Returns the provider's common with the hardfork of a block, which can be
+ * older than the configured one for blocks of the forked network.
+ */
+export async function getBlockCommon(
+  provider: EIP1193Provider,
+  common: Common,
+  chains: HardhatNetworkChainsConfig,
+  blockTag: RpcNewBlockTag
+): Promise<Common> {
+  const { forkedNetwork } = (await provider.request({
+    method: "hardhat_metadata",
+  })) as { forkedNetwork?: { chainId: number; forkBlockNumber: number } };
+
+  if (forkedNetwork === undefined) {
+    return common;
+  }
+
+  const forkBlockNumber = BigInt(forkedNetwork.forkBlockNumber);
+  const blockNumber = await getBlockNumber(provider, blockTag);
+  if (blockNumber > forkBlockNumber) {
+    return common;
+  }
+
+  const hardfork = selectHardfork(
+    forkBlockNumber,
+    common.hardfork(),
+    chains.get(forkedNetwork.chainId)?.hardforkHistory,
+    blockNumber
+  );
+
+  const blockCommon = common.copy();
+  blockCommon.setHardfork(getEthereumJsHardfork(hardfork));
+
+  return blockCommon;
+}
+
+async function getBlockNumber(
+  provider: EIP1193Provider,
+  blockTag: RpcNewBlockTag
+): Promise<bigint> {
+  if (typeof blockTag === "bigint") {
+    return blockTag;
+  }
+
+  if (typeof blockTag === "object" && "blockNumber" in blockTag) {
+    return blockTag.blockNumber;
+  }
+
+  const block =
+    typeof blockTag === "object"
+      ? await provider.request({
+          method: "eth_getBlockByHash",
+          params: [bufferToRpcData(blockTag.blockHash), false],
+        })
+      : await provider.request({
+          method: "eth_getBlockByNumber",
+          params: [blockTag, false],
+        });
+
+  if (block === null) {
+    throw new InvalidArgumentsError("Unknown block");
+  }
+
+  return rpcQuantityToBigInt((block as { number: string }).number);
+}
+
+/**
+ * Traces every transaction of a block. Each `debug_traceTransaction` call
+ * replays the transactions that precede it in the block, so the traces are
+ * the same ones that the block's execution produced.
+ */
+export async function traceBlock(
+  provider: EIP1193Provider,
+  block: any,
+  blockId: string,
+  config: Partial<RpcDebugTracingConfig>
+): Promise<BlockTransactionTrace[]> {
+  if (block === null) {
+    throw new InvalidInputError(`Block ${blockId} doesn't exist`);
+  }
+
+  // Each debug_traceTransaction re-executes the transactions before it in
+  // the block, so tracing a block takes quadratic time in its transactions
+  const traces: BlockTransactionTrace[] = [];
+  for (const txHash of block.transactions as string[]) {
+    const result = await provider.request({
+      method: "debug_traceTransaction",
+      params: config === undefined ? [txHash] : [txHash, config],
+    });
+
+    traces.push({ txHash, result });
+  }
+
+  return traces;
+}
@@ -0,0 +1,70 @@
+import { RpcDebugTraceOutput } from "../output";
+
+export interface CallDiagnostics {
+  // Depth of the deepest call frame that ran code, where the transaction
+  // itself has a depth of 1. It's 0 if the transaction didn't run any code.
+  maxCallDepth: number;
+  // Largest number of calls and contract creations made by a single frame
+  maxCallFanOut: number;
+  // Largest memory size of a call frame, in bytes
+  maxMemorySize: number;
+}
+
+const CALL_OPCODES = new Set([
+  "CALL",
+  "CALLCODE",
+  "CREATE",
+  "CREATE2",
+  "DELEGATECALL",
+  "STATICCALL",
+]);
+
+// Size of each item of the memory of a struct log
+const MEMORY_WORD_SIZE = 32;
+
+/**
+ * Measures how close a transaction got to the protocol limits of the call
+ * depth and memory from the struct logs of its trace. The memory size is taken
+ * from the `memSize` of each step, so the trace doesn't need to include the
+ * memory, unless its steps don't have that field.
+ *
+ * The memory of a step is the one before it runs, so an expansion made by the
+ * last step of a frame isn't measured.
+ */
+export function computeCallDiagnostics(
+  trace: RpcDebugTraceOutput
+): CallDiagnostics {
+  const diagnostics: CallDiagnostics = {
+    maxCallDepth: 0,
+    maxCallFanOut: 0,
+    maxMemorySize: 0,
+  };
+
+  // Number of calls made by the frame that is running at each depth
+  const fanOuts: number[] = [];
+  let previousDepth = 0;
+
+  for (const { depth, op, memSize, memory = [] } of trace.structLogs) {
+    // A step deeper than the previous one is the first of a new frame
+    if (depth > previousDepth) {
+      fanOuts[depth] = 0;
+    }
+    previousDepth = depth;
+
+    diagnostics.maxCallDepth = Math.max(diagnostics.maxCallDepth, depth);
+    diagnostics.maxMemorySize = Math.max(
+      diagnostics.maxMemorySize,
+      memSize ?? memory.length * MEMORY_WORD_SIZE
+    );
+
+    if (CALL_OPCODES.has(op)) {
+      fanOuts[depth] += 1;
+      diagnostics.maxCallFanOut = Math.max(
+        diagnostics.maxCallFanOut,
+        fanOuts[depth]
+      );
+    }
+  }
+
+  return diagnostics;
+}
@@ -0,0 +1,271 @@
+import * as t from "io-ts";
+
+import { numberToRpcQuantity } from "../../../core/jsonrpc/types/base-types";
+import { rpcOldBlockTag } from "../../../core/jsonrpc/types/input/blockTag";
+import { optionalOrNullable } from "../../../util/io-ts";
+import { RpcDebugTraceOutput, RpcStructLog } from "../output";
+
+import { readMemory, stackItem } from "./structLogs";
+
+// A call of `hardhat_diffCalls`, with the same format as the params of
+// `eth_call`. State overrides are only parsed to reject them, as the calls
+// are traced with `debug_traceCall`, which doesn't support them.
+export const rpcDiffedCall = t.type(
+  {
+    call: t.unknown,
+    blockTag: optionalOrNullable(rpcOldBlockTag),
+    stateOverrides: optionalOrNullable(t.unknown),
+  },
+  "RpcDiffedCall"
+);
+
+export type RpcDiffedCall = t.TypeOf<typeof rpcDiffedCall>;
+
+export interface CallLog {
+  address: string;
+  topics: string[];
+  data: string;
+}
+
+export interface CallExecution {
+  success: boolean;
+  returnData: string;
+  gasUsed: string;
+  logs: CallLog[];
+  // Value of each touched storage slot at the end of the call, by address
+  storage: { [address: string]: { [slot: string]: string } };
+}
+
+interface Change<T> {
+  first: T;
+  second: T;
+}
+
+export interface CallExecutionDiff {
+  first: CallExecution;
+  second: CallExecution;
+  // Only the fields that differ between the executions are present
+  changes: {
+    success?: Change<boolean>;
+    returnData?: Change<string>;
+    gasUsed?: Change<string>;
+    logs: Array<{ index: number } & Change<CallLog | null>>;
+    storage: Array<{ address: string; slot: string } & Change<string | null>>;
+  };
+}
+
+interface Frame {
+  // Unknown until a contract creation returns
+  address: string | undefined;
+  parent: Frame | undefined;
+  reverted: boolean;
+}
+
+type FrameEffect =
+  | { frame: Frame; kind: "log"; topics: string[]; data: string }
+  | { frame: Frame; kind: "storage"; slot: string; value: string };
+
+const CALL_OPCODES = new Set([
+  "CALL",
+  "CALLCODE",
+  "DELEGATECALL",
+  "STATICCALL",
+]);
+const CREATE_OPCODES = new Set(["CREATE", "CREATE2"]);
+const LOG_OPCODES = new Set(["LOG0", "LOG1", "LOG2", "LOG3", "LOG4"]);
+
+/**
+ * Summarizes a `debug_traceCall` trace of a call to `to`. The trace must
+ * include the stack and the memory of each step.
+ *
+ * The logs and storage slots of the frames that reverted aren't included.
+ */
+export function summarizeCallTrace(
+  trace: RpcDebugTraceOutput,
+  to: string
+): CallExecution {
+  const effects: FrameEffect[] = [];
+
+  const rootFrame: Frame = {
+    address: to.toLowerCase(),
+    parent: undefined,
+    reverted: trace.failed,
+  };
+
+  // The frame of each depth, starting at 1, and the opcode that created it
+  const frames: Frame[] = [rootFrame];
+  const frameOpcodes: string[] = [""];
+
+  const { structLogs } = trace;
+  for (let i = 0; i < structLogs.length; i++) {
+    const step = structLogs[i];
+    const next = structLogs[i + 1] as RpcStructLog | undefined;
+    const frame = frames[step.depth - 1];
+    const stack = step.stack ?? [];
+
+    if (LOG_OPCODES.has(step.op)) {
+      const topicsCount = Number(step.op.slice(3));
+      const offset = Number(stackItem(stack, 0));
+      const size = Number(stackItem(stack, 1));
+
+      effects.push({
+        frame,
+        kind: "log",
+        topics: Array.from({ length: topicsCount }, (_, j) =>
+          toWord(stackItem(stack, 2 + j))
+        ),
+        data: readMemory(step.memory ?? [], offset, size),
+      });
+    } else if (step.op === "SSTORE") {
+      effects.push({
+        frame,
+        kind: "storage",
+        slot: toWord(stackItem(stack, 0)),
+        value: toWord(stackItem(stack, 1)),
+      });
+    } else if (step.op === "SLOAD" && next?.depth === step.depth) {
+      effects.push({
+        frame,
+        kind: "storage",
+        slot: toWord(stackItem(stack, 0)),
+        value: toWord(stackItem(next.stack ?? [], 0)),
+      });
+    }
+
+    if (next === undefined) {
+      continue;
+    }
+
+    if (next.depth > step.depth) {
+      let address: string | undefined;
+      if (step.op === "CALL" || step.op === "STATICCALL") {
+        address = toAddress(stackItem(stack, 1));
+      } else if (CALL_OPCODES.has(step.op)) {
+        // CALLCODE and DELEGATECALL run in the storage of the caller
+        address = frame.address;
+      }
+
+      frames[step.depth] = { address, parent: frame, reverted: false };
+      frameOpcodes[step.depth] = step.op;
+    } else if (next.depth < step.depth) {
+      // The result of the call or creation is on top of the caller's stack
+      const result = stackItem(next.stack ?? [], 0);
+
+      if (result === 0n) {
+        frame.reverted = true;
+      } else if (CREATE_OPCODES.has(frameOpcodes[step.depth - 1])) {
+        frame.address = toAddress(result);
+      }
+    }
+  }
+
+  const logs: CallLog[] = [];
+  const storage: CallExecution["storage"] = {};
+
+  for (const effect of effects) {
+    if (isReverted(effect.frame) || effect.frame.address === undefined) {
+      continue;
+    }
+
+    if (effect.kind === "log") {
+      logs.push({
+        address: effect.frame.address,
+        topics: effect.topics,
+        data: effect.data,
+      });
+    } else {
+      storage[effect.frame.address] ??= {};
+      storage[effect.frame.address][effect.slot] = effect.value;
+    }
+  }
+
+  return {
+    success: !trace.failed,
+    returnData: `0x${trace.returnValue}`,
+    gasUsed: numberToRpcQuantity(trace.gas),
+    logs,
+    storage,
+  };
+}
+
+/**
+ * Compares the executions of two calls.
+ */
+export function diffCallExecutions(
+  first: CallExecution,
+  second: CallExecution
+): CallExecutionDiff {
+  const changes: CallExecutionDiff["changes"] = { logs: [], storage: [] };
+
+  if (first.success !== second.success) {
+    changes.success = { first: first.success, second: second.success };
+  }
+
+  if (first.returnData !== second.returnData) {
+    changes.returnData = { first: first.returnData, second: second.returnData };
+  }
+
+  if (first.gasUsed !== second.gasUsed) {
+    changes.gasUsed = { first: first.gasUsed, second: second.gasUsed };
+  }
+
+  const logsCount = Math.max(first.logs.length, second.logs.length);
+  for (let index = 0; index < logsCount; index++) {
+    const firstLog = first.logs[index] ?? null;
+    const secondLog = second.logs[index] ?? null;
+
+    if (JSON.stringify(firstLog) !== JSON.stringify(secondLog)) {
+      changes.logs.push({ index, first: firstLog, second: secondLog });
+    }
+  }
+
+  const addresses = new Set([
+    ...Object.keys(first.storage),
+    ...Object.keys(second.storage),
+  ]);
+
+  for (const address of addresses) {
+    const firstSlots = first.storage[address] ?? {};
+    const secondSlots = second.storage[address] ?? {};
+
+    const slots = new Set([
+      ...Object.keys(firstSlots),
+      ...Object.keys(secondSlots),
+    ]);
+
+    for (const slot of slots) {
+      const firstValue = firstSlots[slot] ?? null;
+      const secondValue = secondSlots[slot] ?? null;
+
+      if (firstValue !== secondValue) {
+        changes.storage.push({
+          address,
+          slot,
+          first: firstValue,
+          second: secondValue,
+        });
+      }
+    }
+  }
+
+  return { first, second, changes };
+}
+
+function isReverted(frame: Frame | undefined): boolean {
+  for (let f = frame; f !== undefined; f = f.parent) {
+    if (f.reverted) {
+      return true;
+    }
+  }
+
+  return false;
+}
+
+function toWord(value: bigint): string {
+  return `0x${value.toString(16).padStart(64, "0")}`;
+}
+
+function toAddress(value: bigint): string {
+  const address = value & ((1n << 160n) - 1n);
+  return `0x${address.toString(16).padStart(40, "0")}`;
+}
@@ -0,0 +1,326 @@
+import { numberToRpcQuantity } from "../../../core/jsonrpc/types/base-types";
+import { RpcDebugTraceOutput, RpcStructLog } from "../output";
+
+import { readMemory, stackItem } from "./structLogs";
+
+export const CALL_TRACER = "callTracer";
+
+// A call frame in the format of Geth's `callTracer`
+export interface CallFrame {
+  type: string;
+  from?: string;
+  to?: string;
+  value?: string;
+  gas?: string;
+  gasUsed: string;
+  input: string;
+  output?: string;
+  error?: string;
+  calls?: CallFrame[];
+}
+
+// The transaction or call that was traced, which becomes the top-level frame
+export interface TracedCall {
+  type: "CALL" | "CREATE";
+  from: string;
+  // The address of the deployed contract, if it's a contract creation
+  to?: string;
+  value: string;
+  gas?: string;
+  input: string;
+}
+
+interface OpenFrame {
+  frame: CallFrame;
+  depth: number;
+  // Address of the account whose code runs in the frame, which is the
+  // caller of its calls
+  address?: string;
+  gas: bigint;
+  lastStep?: RpcStructLog;
+}
+
+const CALL_OPCODES = new Set([
+  "CALL",
+  "CALLCODE",
+  "CREATE",
+  "CREATE2",
+  "DELEGATECALL",
+  "STATICCALL",
+]);
+
+const ADDRESS_MASK = (1n << 160n) - 1n;
+
+// Gas given to the callee of a call that transfers value, on top of the gas
+// that the call sends
+const CALL_STIPEND = 2300n;
+
+/**
+ * Rebuilds the tree of call frames of a trace, like Geth's `callTracer`.
+ *
+ * The struct logs only have the steps of frames that run code, so the `gas`
+ * of calls to precompiles and accounts without code is estimated from the
+ * gas of their caller, and their `gasUsed` is 0.
+ */
+export function computeCallFrames(
+  trace: RpcDebugTraceOutput,
+  tracedCall: TracedCall
+): CallFrame {
+  const { structLogs } = trace;
+
+  const top: CallFrame = {
+    type: tracedCall.type,
+    from: tracedCall.from,
+    value: tracedCall.value,
+    gasUsed: numberToRpcQuantity(trace.gas),
+    input: tracedCall.input,
+  };
+
+  if (tracedCall.to !== undefined) {
+    top.to = tracedCall.to;
+  }
+
+  if (tracedCall.gas !== undefined) {
+    top.gas = tracedCall.gas;
+  }
+
+  if (trace.returnValue !== "") {
+    top.output = `0x${trace.returnValue}`;
+  }
+
+  const resultSteps = findResultSteps(structLogs);
+  const open: OpenFrame[] = [
+    { frame: top, depth: 1, address: tracedCall.to, gas: 0n },
+  ];
+
+  for (let i = 0; i < structLogs.length; i++) {
+    const step = structLogs[i];
+
+    while (open.length > 1 && open[open.length - 1].depth > step.depth) {
+      closeFrame(open.pop()!);
+    }
+
+    const current = open[open.length - 1];
+    if (current.depth === step.depth) {
+      current.lastStep = step;
+    }
+
+    // A call that fails before running, like one that runs out of gas
+    // expanding the memory, ends the frame of its caller instead
+    if (!CALL_OPCODES.has(step.op) || step.error !== undefined) {
+      continue;
+    }
+
+    const resultStep = resultSteps.get(i);
+    const child = createFrame(step, current.address, resultStep);
+    (current.frame.calls ??= []).push(child);
+
+    const address =
+      step.op === "DELEGATECALL" || step.op === "CALLCODE"
+        ? current.address
+        : child.to;
+
+    const next = structLogs[i + 1];
+    if (next !== undefined && next.depth === step.depth + 1) {
+      open.push({
+        frame: child,
+        depth: next.depth,
+        address,
+        gas: BigInt(next.gas),
+      });
+      child.gas = numberToRpcQuantity(next.gas);
+    } else {
+      child.gas = numberToRpcQuantity(estimateCallGas(step));
+      child.gasUsed = numberToRpcQuantity(0);
+    }
+
+    // Calls push 0 if they fail, and so do creations instead of the address
+    // of the contract. The error of a frame that ran code is replaced with
+    // a more specific one when it's closed.
+    if (
+      resultStep !== undefined &&
+      stackItem(resultStep.stack ?? [], 0) === 0n
+    ) {
+      child.error = "execution failed";
+    }
+  }
+
+  while (open.length > 1) {
+    closeFrame(open.pop()!);
+  }
+
+  if (trace.failed) {
+    top.error = getFrameError(open[0].lastStep) ?? "execution failed";
+  }
+
+  return top;
+}
+
+/**
+ * Returns the step that runs after each call returns, in the frame of its
+ * caller, keyed by the index of the call. The result of the call is on top
+ * of its stack.
+ */
+function findResultSteps(
+  structLogs: RpcStructLog[]
+): Map<number, RpcStructLog> {
+  const resultSteps = new Map<number, RpcStructLog>();
+  const pendingCalls: number[] = [];
+
+  for (let i = 0; i < structLogs.length; i++) {
+    const { depth, op } = structLogs[i];
+
+    while (
+      pendingCalls.length > 0 &&
+      structLogs[pendingCalls[pendingCalls.length - 1]].depth >= depth
+    ) {
+      const callIndex = pendingCalls.pop()!;
+
+      // The frame of the caller may have ended instead
+      if (structLogs[callIndex].depth === depth) {
+        resultSteps.set(callIndex, structLogs[i]);
+      }
+    }
+
+    if (CALL_OPCODES.has(op)) {
+      pendingCalls.push(i);
+    }
+  }
+
+  return resultSteps;
+}
+
+function createFrame(
+  step: RpcStructLog,
+  from: string | undefined,
+  resultStep: RpcStructLog | undefined
+): CallFrame {
+  const { op, memory = [] } = step;
+  const stack = step.stack ?? [];
+
+  const frame: CallFrame = {
+    type: op,
+    gasUsed: numberToRpcQuantity(0),
+    input: "0x",
+  };
+
+  // The caller is unknown if it's a contract whose creation failed
+  if (from !== undefined) {
+    frame.from = from;
+  }
+
+  if (op === "CREATE" || op === "CREATE2") {
+    frame.value = numberToRpcQuantity(stackItem(stack, 0));
+    frame.input = readMemory(
+      memory,
+      Number(stackItem(stack, 1)),
+      Number(stackItem(stack, 2))
+    );
+
+    // The address of the new contract is the result of the creation, which
+    // is 0 if it failed
+    const created =
+      resultStep !== undefined ? stackItem(resultStep.stack ?? [], 0) : 0n;
+    if (created !== 0n) {
+      frame.to = toAddress(created);
+    }
+
+    return frame;
+  }
+
+  frame.to = toAddress(stackItem(stack, 1));
+
+  let argsIndex = 2;
+  if (op === "CALL" || op === "CALLCODE") {
+    frame.value = numberToRpcQuantity(stackItem(stack, 2));
+    argsIndex = 3;
+  }
+
+  frame.input = readMemory(
+    memory,
+    Number(stackItem(stack, argsIndex)),
+    Number(stackItem(stack, argsIndex + 1))
+  );
+
+  return frame;
+}
+
+function closeFrame({ frame, gas, lastStep }: OpenFrame) {
+  const error = getFrameError(lastStep);
+
+  // Frames that end with an error other than a revert use all their gas
+  let gasLeft = 0n;
+  if (lastStep !== undefined && lastStep.error === undefined) {
+    gasLeft = BigInt(lastStep.gas - lastStep.gasCost);
+  }
+
+  frame.gasUsed = numberToRpcQuantity(gas - gasLeft);
+
+  if (
+    lastStep !== undefined &&
+    lastStep.error === undefined &&
+    (lastStep.op === "RETURN" || lastStep.op === "REVERT")
+  ) {
+    const stack = lastStep.stack ?? [];
+    const output = readMemory(
+      lastStep.memory ?? [],
+      Number(stackItem(stack, 0)),
+      Number(stackItem(stack, 1))
+    );
+
+    if (output !== "0x") {
+      frame.output = output;
+    }
+  }
+
+  if (error !== undefined) {
+    frame.error = error;
+  }
+}
+
+function getFrameError(
+  lastStep: RpcStructLog | undefined
+): string | undefined {
+  if (lastStep === undefined) {
+    return undefined;
+  }
+
+  if (lastStep.error !== undefined) {
+    const { message } = lastStep.error as { message?: string };
+    return message ?? "execution failed";
+  }
+
+  if (lastStep.op === "REVERT") {
+    return "execution reverted";
+  }
+
+  return undefined;
+}
+
+/**
+ * Estimates the gas given to a call that doesn't run code, which is the gas
+ * it sends, limited to all but one 64th of the gas of its caller.
+ */
+function estimateCallGas(step: RpcStructLog): bigint {
+  const stack = step.stack ?? [];
+  const available = BigInt(step.gas);
+
+  let gas = stackItem(stack, 0);
+  const limit = available - available / 64n;
+  if (gas > limit) {
+    gas = limit;
+  }
+
+  if (
+    (step.op === "CALL" || step.op === "CALLCODE") &&
+    stackItem(stack, 2) > 0n
+  ) {
+    gas += CALL_STIPEND;
+  }
+
+  return gas;
+}
+
+function toAddress(item: bigint): string {
+  return `0x${(item & ADDRESS_MASK).toString(16).padStart(40, "0")}`;
+}
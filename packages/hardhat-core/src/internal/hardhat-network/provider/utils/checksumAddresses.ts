@@ -0,0 +1,74 @@
+import type EthereumjsUtilT from "@nomicfoundation/ethereumjs-util";
+
+const ADDRESS_REGEX = /^0x[0-9a-fA-F]{40}$/;
+
+// Fields of blocks, transactions, receipts, logs, access lists and
+// withdrawals that hold an address
+const ADDRESS_FIELDS = new Set([
+  "address",
+  "contractAddress",
+  "from",
+  "miner",
+  "to",
+]);
+
+const ADDRESS_RESULT_METHODS = new Set(["eth_coinbase"]);
+
+const ADDRESS_LIST_RESULT_METHODS = new Set([
+  "eth_accounts",
+  "eth_requestAccounts",
+]);
+
+/**
+ * Returns a copy of the result of a JSON-RPC method with every address
+ * converted to its EIP-55 checksummed form.
+ */
+export function checksumAddressesInResult(
+  method: string,
+  result: unknown
+): unknown {
+  if (ADDRESS_RESULT_METHODS.has(method)) {
+    return checksumIfAddress(result);
+  }
+
+  if (ADDRESS_LIST_RESULT_METHODS.has(method) && Array.isArray(result)) {
+    return result.map(checksumIfAddress);
+  }
+
+  return checksumAddressFields(result);
+}
+
+/**
+ * Returns a copy of `value` where the known address fields of every nested
+ * object are checksummed.
+ */
+export function checksumAddressFields(value: unknown): unknown {
+  if (Array.isArray(value)) {
+    return value.map(checksumAddressFields);
+  }
+
+  if (value !== null && typeof value === "object") {
+    const checksummed: Record<string, unknown> = {};
+
+    for (const [key, fieldValue] of Object.entries(value)) {
+      checksummed[key] = ADDRESS_FIELDS.has(key)
+        ? checksumIfAddress(fieldValue)
+        : checksumAddressFields(fieldValue);
+    }
+
+    return checksummed;
+  }
+
+  return value;
+}
+
+function checksumIfAddress(value: unknown): unknown {
+  if (typeof value !== "string" || !ADDRESS_REGEX.test(value)) {
+    return value;
+  }
+
+  const { toChecksumAddress } =
+    require("@nomicfoundation/ethereumjs-util") as typeof EthereumjsUtilT;
+
+  return toChecksumAddress(value);
+}
@@ -0,0 +1,159 @@
+import type { Common } from "@nomicfoundation/ethereumjs-common";
+
+import * as t from "io-ts";
+
+import {
+  bufferToRpcData,
+  rpcAddress,
+  rpcData,
+} from "../../../core/jsonrpc/types/base-types";
+import { HardforkName } from "../../../util/hardforks";
+import { getPushLength, isPush, Opcode } from "../../stack-traces/opcodes";
+
+// The hardfork that introduced each opcode that isn't part of Frontier
+export const OPCODE_HARDFORKS: Partial<Record<number, HardforkName>> = {
+  [Opcode.DELEGATECALL]: HardforkName.HOMESTEAD,
+  [Opcode.RETURNDATASIZE]: HardforkName.BYZANTIUM,
+  [Opcode.RETURNDATACOPY]: HardforkName.BYZANTIUM,
+  [Opcode.STATICCALL]: HardforkName.BYZANTIUM,
+  [Opcode.REVERT]: HardforkName.BYZANTIUM,
+  [Opcode.SHL]: HardforkName.CONSTANTINOPLE,
+  [Opcode.SHR]: HardforkName.CONSTANTINOPLE,
+  [Opcode.SAR]: HardforkName.CONSTANTINOPLE,
+  [Opcode.EXTCODEHASH]: HardforkName.CONSTANTINOPLE,
+  [Opcode.CREATE2]: HardforkName.CONSTANTINOPLE,
+  [Opcode.CHAINID]: HardforkName.ISTANBUL,
+  [Opcode.SELFBALANCE]: HardforkName.ISTANBUL,
+  [Opcode.BASEFEE]: HardforkName.LONDON,
+  0x5f: HardforkName.SHANGHAI,
+  0x49: HardforkName.CANCUN,
+  0x4a: HardforkName.CANCUN,
+  0x5c: HardforkName.CANCUN,
+  0x5d: HardforkName.CANCUN,
+  0x5e: HardforkName.CANCUN,
+};
+
+// Names of the opcodes that the stack traces don't use, so they are still
+// unallocated in their `Opcode` enum
+const NEWER_OPCODE_NAMES: Partial<Record<number, string>> = {
+  0x49: "BLOBHASH",
+  0x4a: "BLOBBASEFEE",
+  0x5c: "TLOAD",
+  0x5d: "TSTORE",
+  0x5e: "MCOPY",
+  0x5f: "PUSH0",
+};
+
+const TERMINATING_OPCODES = new Set<Opcode>([
+  Opcode.STOP,
+  Opcode.JUMP,
+  Opcode.JUMPI,
+  Opcode.RETURN,
+  Opcode.REVERT,
+  Opcode.INVALID,
+  Opcode.SELFDESTRUCT,
+]);
+
+// The first param of `debug_disassemble`: the address of an account, or the
+// code to disassemble
+export const rpcDisassemblyTarget = t.union(
+  [t.type({ address: rpcAddress }), t.type({ code: rpcData })],
+  "RpcDisassemblyTarget"
+);
+
+export type RpcDisassemblyTarget = t.TypeOf<typeof rpcDisassemblyTarget>;
+
+export interface DisassembledInstruction {
+  pc: number;
+  opcode: string;
+  // Only present in PUSH1 to PUSH32. It can be shorter than expected if the
+  // code ends in the middle of it.
+  pushData?: string;
+}
+
+export interface BasicBlock {
+  // The pcs of the first and last instructions of the block
+  start: number;
+  end: number;
+}
+
+export interface Disassembly {
+  instructions: DisassembledInstruction[];
+  basicBlocks: BasicBlock[];
+}
+
+/**
+ * Disassembles EVM bytecode using the opcodes of the hardfork of `common`.
+ * Opcodes that aren't active in that hardfork are reported as unrecognized.
+ *
+ * Basic blocks start at the first instruction, at each JUMPDEST, and after
+ * each instruction that stops or jumps.
+ */
+export function disassemble(code: Buffer, common: Common): Disassembly {
+  const instructions: DisassembledInstruction[] = [];
+  const basicBlocks: BasicBlock[] = [];
+
+  let blockStart: number | undefined;
+  let pc = 0;
+  while (pc < code.length) {
+    const opcode: Opcode = code[pc];
+    const recognized = isRecognized(opcode, common);
+
+    if (opcode === Opcode.JUMPDEST && blockStart !== undefined) {
+      basicBlocks.push({ start: blockStart, end: lastPc(instructions) });
+      blockStart = undefined;
+    }
+
+    blockStart ??= pc;
+
+    const instruction: DisassembledInstruction = {
+      pc,
+      opcode: recognized ? getOpcodeName(opcode)! : unrecognizedName(opcode),
+    };
+
+    let length = 1;
+    if (isPush(opcode)) {
+      length += getPushLength(opcode);
+      instruction.pushData = bufferToRpcData(
+        code.subarray(pc + 1, pc + length)
+      );
+    }
+
+    instructions.push(instruction);
+
+    if (!recognized || TERMINATING_OPCODES.has(opcode)) {
+      basicBlocks.push({ start: blockStart, end: pc });
+      blockStart = undefined;
+    }
+
+    pc += length;
+  }
+
+  if (blockStart !== undefined) {
+    basicBlocks.push({ start: blockStart, end: lastPc(instructions) });
+  }
+
+  return { instructions, basicBlocks };
+}
+
+function isRecognized(opcode: Opcode, common: Common): boolean {
+  const name = getOpcodeName(opcode);
+  if (name === undefined || name.startsWith("UNRECOGNIZED_")) {
+    return false;
+  }
+
+  const hardfork = OPCODE_HARDFORKS[opcode];
+  return hardfork === undefined || common.gteHardfork(hardfork);
+}
+
+function getOpcodeName(opcode: Opcode): string | undefined {
+  return NEWER_OPCODE_NAMES[opcode] ?? Opcode[opcode];
+}
+
+function unrecognizedName(opcode: Opcode): string {
+  return `UNRECOGNIZED_${opcode.toString(16).padStart(2, "0").toUpperCase()}`;
+}
+
+function lastPc(instructions: DisassembledInstruction[]): number {
+  return instructions[instructions.length - 1].pc;
+}
@@ -0,0 +1,65 @@
+import { InvalidInputError } from "../../../core/providers/errors";
+
+export interface FaucetRateLimit {
+  maxAmount: bigint;
+  // In seconds
+  interval: number;
+}
+
+interface FaucetRequest {
+  timestamp: number;
+  amount: bigint;
+}
+
+/**
+ * Limits the amount of wei that each address can get from
+ * `hardhat_requestFunds` within a sliding time window.
+ */
+export class FaucetRateLimiter {
+  private readonly _requests = new Map<string, FaucetRequest[]>();
+
+  constructor(
+    private readonly _limit: FaucetRateLimit,
+    private readonly _now: () => number = Date.now
+  ) {}
+
+  /**
+   * Throws if a request of `amount` wei for `address` would exceed the
+   * address' limit.
+   */
+  public assertCanRequest(address: string, amount: bigint) {
+    const requestedAmount = this._getRecentRequests(address).reduce(
+      (sum, request) => sum + request.amount,
+      0n
+    );
+
+    if (requestedAmount + amount > this._limit.maxAmount) {
+      // eslint-disable-next-line @nomicfoundation/hardhat-internal-rules/only-hardhat-error
+      throw new InvalidInputError(
+        `Faucet limit exceeded: ${address} can't get more than ${this._limit.maxAmount} wei every ${this._limit.interval} seconds`
+      );
+    }
+  }
+
+  /**
+   * Records a request of `amount` wei for `address` that was fulfilled.
+   */
+  public registerRequest(address: string, amount: bigint) {
+    const recentRequests = this._getRecentRequests(address);
+    recentRequests.push({ timestamp: this._now(), amount });
+
+    this._requests.set(address.toLowerCase(), recentRequests);
+  }
+
+  public clear() {
+    this._requests.clear();
+  }
+
+  private _getRecentRequests(address: string): FaucetRequest[] {
+    const windowStart = this._now() - this._limit.interval * 1000;
+
+    return (this._requests.get(address.toLowerCase()) ?? []).filter(
+      (request) => request.timestamp > windowStart
+    );
+  }
+}
@@ -0,0 +1,196 @@
+import { numberToRpcQuantity } from "../../../core/jsonrpc/types/base-types";
+
+import { CallFrame } from "./callTracer";
+
+export const FLAT_CALL_TRACER = "flatCallTracer";
+
+// The block and position of a traced transaction
+export interface FlatTraceContext {
+  blockHash: string;
+  blockNumber: number;
+  transactionHash: string;
+  transactionPosition: number;
+}
+
+interface FlatCallAction {
+  callType: string;
+  from?: string;
+  to?: string;
+  gas?: string;
+  input: string;
+  value: string;
+}
+
+interface FlatCreateAction {
+  creationMethod: string;
+  from?: string;
+  gas?: string;
+  init: string;
+  value: string;
+}
+
+interface FlatRewardAction {
+  author: string;
+  rewardType: "block";
+  value: string;
+}
+
+interface FlatCallResult {
+  gasUsed: string;
+  output: string;
+}
+
+interface FlatCreateResult {
+  address?: string;
+  code: string;
+  gasUsed: string;
+}
+
+// A call frame in the flat format of OpenEthereum's and Erigon's traces
+export interface FlatTrace extends Partial<FlatTraceContext> {
+  type: "call" | "create" | "reward";
+  action: FlatCallAction | FlatCreateAction | FlatRewardAction;
+  result?: FlatCallResult | FlatCreateResult | null;
+  error?: string;
+  subtraces: number;
+  traceAddress: number[];
+}
+
+const PARITY_ERRORS: Record<string, string> = {
+  "execution reverted": "Reverted",
+  "out of gas": "Out of gas",
+  "contract creation code storage out of gas": "Out of gas",
+  "max code size exceeded": "Out of gas",
+  "invalid jump destination": "Bad jump destination",
+  "return data out of bounds": "Out of bounds",
+  "stack limit reached 1024 (1023)": "Out of stack",
+};
+
+const PARITY_ERROR_PREFIXES: Array<[string, string]> = [
+  ["invalid opcode", "Bad instruction"],
+  ["stack underflow", "Out of stack"],
+];
+
+/**
+ * Returns the context of the flat traces of a mined transaction, given the
+ * transaction as returned by `eth_getTransactionByHash`.
+ */
+export function getFlatTraceContext(tx: {
+  blockHash: string;
+  blockNumber: string;
+  hash: string;
+  transactionIndex: string;
+}): FlatTraceContext {
+  return {
+    blockHash: tx.blockHash,
+    blockNumber: Number(tx.blockNumber),
+    transactionHash: tx.hash,
+    transactionPosition: Number(tx.transactionIndex),
+  };
+}
+
+/**
+ * Flattens a tree of call frames into a list of traces ordered like the
+ * calls, where each trace has the path of indexes of its call in the tree,
+ * like the flat traces of OpenEthereum and Erigon.
+ */
+export function flattenCallFrames(
+  frame: CallFrame,
+  context?: FlatTraceContext
+): FlatTrace[] {
+  const traces: FlatTrace[] = [];
+  addFlatTraces(traces, frame, [], context);
+  return traces;
+}
+
+/**
+ * Returns the pseudo-trace of the reward paid to the miner of a block, given
+ * the block as returned by `eth_getBlockByNumber`.
+ */
+export function getBlockRewardTrace(
+  block: { hash: string; number: string; miner: string },
+  reward: bigint
+): FlatTrace {
+  return {
+    type: "reward",
+    action: {
+      author: block.miner,
+      rewardType: "block",
+      value: numberToRpcQuantity(reward),
+    },
+    result: null,
+    subtraces: 0,
+    traceAddress: [],
+    blockHash: block.hash,
+    blockNumber: Number(block.number),
+  };
+}
+
+function addFlatTraces(
+  traces: FlatTrace[],
+  frame: CallFrame,
+  traceAddress: number[],
+  context: FlatTraceContext | undefined
+) {
+  const calls = frame.calls ?? [];
+  const isCreate = frame.type === "CREATE" || frame.type === "CREATE2";
+
+  const trace: FlatTrace = {
+    type: isCreate ? "create" : "call",
+    action: isCreate
+      ? {
+          creationMethod: frame.type.toLowerCase(),
+          from: frame.from,
+          gas: frame.gas,
+          init: frame.input,
+          value: frame.value ?? "0x0",
+        }
+      : {
+          callType: frame.type.toLowerCase(),
+          from: frame.from,
+          to: frame.to,
+          gas: frame.gas,
+          input: frame.input,
+          value: frame.value ?? "0x0",
+        },
+    subtraces: calls.length,
+    traceAddress,
+    ...context,
+  };
+
+  // The output of a reverted call has its revert reason, so it's kept
+  if (frame.error === undefined || frame.error === "execution reverted") {
+    trace.result = isCreate
+      ? {
+          address: frame.to,
+          code: frame.output ?? "0x",
+          gasUsed: frame.gasUsed,
+        }
+      : { gasUsed: frame.gasUsed, output: frame.output ?? "0x" };
+  }
+
+  if (frame.error !== undefined) {
+    trace.error = toParityError(frame.error);
+  }
+
+  traces.push(trace);
+
+  for (const [i, call] of calls.entries()) {
+    addFlatTraces(traces, call, [...traceAddress, i], context);
+  }
+}
+
+function toParityError(error: string): string {
+  const parityError = PARITY_ERRORS[error];
+  if (parityError !== undefined) {
+    return parityError;
+  }
+
+  for (const [prefix, prefixError] of PARITY_ERROR_PREFIXES) {
+    if (error.startsWith(prefix)) {
+      return prefixError;
+    }
+  }
+
+  return error;
+}
@@ -0,0 +1,66 @@
+import { RpcDebugTraceOutput } from "../output";
+
+import { readMemory, stackItem } from "./structLogs";
+
+export const FOUR_BYTE_TRACER = "4byteTracer";
+
+// Number of calls per selector and calldata size, keyed like Geth does it:
+// `<selector>-<calldata size without the selector>`
+export interface FourByteStatistics {
+  [selectorAndSize: string]: number;
+}
+
+// The highest address of a precompile, which isn't counted by the tracer
+const LAST_PRECOMPILE_ADDRESS = 0x0an;
+
+/**
+ * Counts the calls of a trace per function selector and calldata size, like
+ * Geth's `4byteTracer`. The input of the traced transaction or call is
+ * counted too, unless it's a contract creation, in which case `input` should
+ * be `undefined`.
+ */
+export function computeFourByteStatistics(
+  trace: RpcDebugTraceOutput,
+  input: string | undefined
+): FourByteStatistics {
+  const statistics: FourByteStatistics = {};
+
+  const countCall = (selector: string, size: number) => {
+    // Calls without a full selector aren't counted
+    if (size < 4) {
+      return;
+    }
+
+    const key = `${selector.toLowerCase()}-${size - 4}`;
+    statistics[key] = (statistics[key] ?? 0) + 1;
+  };
+
+  if (input !== undefined) {
+    countCall(input.slice(0, 10), (input.length - 2) / 2);
+  }
+
+  for (const { op, stack = [], memory = [] } of trace.structLogs) {
+    let argsIndex: number;
+    if (op === "CALL" || op === "CALLCODE") {
+      argsIndex = 3;
+    } else if (op === "DELEGATECALL" || op === "STATICCALL") {
+      argsIndex = 2;
+    } else {
+      continue;
+    }
+
+    const address = stackItem(stack, 1) & ((1n << 160n) - 1n);
+    if (address > 0n && address <= LAST_PRECOMPILE_ADDRESS) {
+      continue;
+    }
+
+    const offset = Number(stackItem(stack, argsIndex));
+    const size = Number(stackItem(stack, argsIndex + 1));
+
+    // Only the selector is read, as the size of the input can be huge in
+    // calls that run out of gas expanding the memory
+    countCall(readMemory(memory, offset, 4), size);
+  }
+
+  return statistics;
+}
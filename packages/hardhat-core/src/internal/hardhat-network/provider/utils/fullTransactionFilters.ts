@@ -0,0 +1,53 @@
+/**
+ * Keeps the ids of the pending transaction filters and subscriptions that
+ * were created with the `fullTransactions` flag, whose changes and
+ * notifications have to include whole transactions instead of their hashes.
+ */
+export class FullTransactionFilters {
+  private readonly _ids = new Set<string>();
+
+  /**
+   * Removes the `fullTransactions` flag from the params of
+   * `eth_newPendingTransactionFilter` and
+   * `eth_subscribe("newPendingTransactions")` requests, returning the new
+   * params and the flag.
+   */
+  public extractFlag(method: string, params: any[]): [any[], boolean] {
+    let index: number;
+    if (method === "eth_newPendingTransactionFilter") {
+      index = 0;
+    } else if (
+      method === "eth_subscribe" &&
+      params[0] === "newPendingTransactions"
+    ) {
+      index = 1;
+    } else {
+      return [params, false];
+    }
+
+    // Anything else is left for the method's own validation
+    if (params.length !== index + 1 || typeof params[index] !== "boolean") {
+      return [params, false];
+    }
+
+    return [params.slice(0, index), params[index]];
+  }
+
+  public add(id: string) {
+    this._ids.add(id.toLowerCase());
+  }
+
+  public has(id: unknown): boolean {
+    return typeof id === "string" && this._ids.has(id.toLowerCase());
+  }
+
+  public remove(id: unknown) {
+    if (typeof id === "string") {
+      this._ids.delete(id.toLowerCase());
+    }
+  }
+
+  public clear() {
+    this._ids.clear();
+  }
+}
@@ -0,0 +1,142 @@
+import type { EIP1193Provider } from "../../../../types";
+
+import * as t from "io-ts";
+
+import {
+  numberToRpcQuantity,
+  rpcQuantity,
+  rpcQuantityToBigInt,
+} from "../../../core/jsonrpc/types/base-types";
+import { optionalOrNullable } from "../../../util/io-ts";
+
+// Hardhat Network's default priority fee, used when there is nothing else to
+// base a suggestion on
+const DEFAULT_MAX_PRIORITY_FEE_PER_GAS = 1_000_000_000n;
+
+export const rpcGasPriceOracleConfig = t.union(
+  [
+    t.type({ strategy: t.literal("default") }),
+    t.type({
+      strategy: t.literal("fixed"),
+      gasPrice: rpcQuantity,
+      maxPriorityFeePerGas: rpcQuantity,
+    }),
+    t.type({
+      strategy: t.literal("percentile"),
+      blocks: t.number,
+      percentile: t.number,
+      fallbackMaxPriorityFeePerGas: optionalOrNullable(rpcQuantity),
+    }),
+    t.type({ strategy: t.literal("remote") }),
+  ],
+  "RpcGasPriceOracleConfig"
+);
+
+export type RpcGasPriceOracleConfig = t.TypeOf<typeof rpcGasPriceOracleConfig>;
+
+export interface PercentileGasPriceOracleConfig {
+  blocks: number;
+  percentile: number;
+  fallbackMaxPriorityFeePerGas?: bigint;
+}
+
+/**
+ * Suggests the values returned by `eth_gasPrice` and
+ * `eth_maxPriorityFeePerGas`.
+ */
+export interface GasPriceOracle {
+  getGasPrice(): Promise<bigint>;
+  getMaxPriorityFeePerGas(): Promise<bigint>;
+}
+
+export class FixedGasPriceOracle implements GasPriceOracle {
+  constructor(
+    private readonly _gasPrice: bigint,
+    private readonly _maxPriorityFeePerGas: bigint
+  ) {}
+
+  public async getGasPrice(): Promise<bigint> {
+    return this._gasPrice;
+  }
+
+  public async getMaxPriorityFeePerGas(): Promise<bigint> {
+    return this._maxPriorityFeePerGas;
+  }
+}
+
+/**
+ * Suggests the average of the given percentile of the priority fees paid in
+ * each of the last blocks, ignoring the empty ones. The gas price is that fee
+ * on top of the next block's base fee.
+ */
+export class PercentileGasPriceOracle implements GasPriceOracle {
+  constructor(
+    private readonly _provider: EIP1193Provider,
+    private readonly _blocks: number,
+    private readonly _percentile: number,
+    // Suggested if all of those blocks are empty
+    private readonly _fallbackMaxPriorityFeePerGas = DEFAULT_MAX_PRIORITY_FEE_PER_GAS
+  ) {}
+
+  public async getGasPrice(): Promise<bigint> {
+    const feeHistory = await this._getFeeHistory();
+    const nextBaseFeePerGas = rpcQuantityToBigInt(
+      feeHistory.baseFeePerGas[feeHistory.baseFeePerGas.length - 1]
+    );
+
+    return nextBaseFeePerGas + this._averageReward(feeHistory);
+  }
+
+  public async getMaxPriorityFeePerGas(): Promise<bigint> {
+    return this._averageReward(await this._getFeeHistory());
+  }
+
+  private async _getFeeHistory(): Promise<RpcFeeHistory> {
+    return (await this._provider.request({
+      method: "eth_feeHistory",
+      params: [numberToRpcQuantity(this._blocks), "latest", [this._percentile]],
+    })) as RpcFeeHistory;
+  }
+
+  private _averageReward({ gasUsedRatio, reward }: RpcFeeHistory): bigint {
+    const rewards = reward
+      .filter((_, i) => gasUsedRatio[i] > 0)
+      .map(([blockReward]) => rpcQuantityToBigInt(blockReward));
+
+    if (rewards.length === 0) {
+      return this._fallbackMaxPriorityFeePerGas;
+    }
+
+    return (
+      rewards.reduce((sum, blockReward) => sum + blockReward, 0n) /
+      BigInt(rewards.length)
+    );
+  }
+}
+
+/**
+ * Suggests the same values as a remote node, like the one being forked.
+ */
+export class RemoteGasPriceOracle implements GasPriceOracle {
+  constructor(private readonly _remote: EIP1193Provider) {}
+
+  public async getGasPrice(): Promise<bigint> {
+    return rpcQuantityToBigInt(
+      (await this._remote.request({ method: "eth_gasPrice" })) as string
+    );
+  }
+
+  public async getMaxPriorityFeePerGas(): Promise<bigint> {
+    return rpcQuantityToBigInt(
+      (await this._remote.request({
+        method: "eth_maxPriorityFeePerGas",
+      })) as string
+    );
+  }
+}
+
+interface RpcFeeHistory {
+  baseFeePerGas: string[];
+  gasUsedRatio: number[];
+  reward: string[][];
+}
@@ -0,0 +1,64 @@
+import { numberToRpcQuantity } from "../../../core/jsonrpc/types/base-types";
+
+import { CallFrame } from "./callTracer";
+
+export const GAS_PROFILE_TRACER = "gasProfileTracer";
+
+// The gas used by a call frame, identified by its contract and function
+export interface GasProfileFrame {
+  type: string;
+  // The account whose code runs in the frame, unless it's a failed creation
+  address?: string;
+  // The selector of the called function, if the input has one
+  selector?: string;
+  gasUsed: string;
+  // The gas used by the frame, without the gas used by its calls
+  selfGasUsed: string;
+  calls?: GasProfileFrame[];
+}
+
+const SELECTOR_LENGTH = 4;
+
+/**
+ * Returns the gas used by each call frame of a tree of call frames, and by
+ * the code of each frame alone.
+ *
+ * The `gasUsed` of the top-level frame includes the intrinsic gas of the
+ * transaction, so its `selfGasUsed` does too.
+ */
+export function computeGasProfile(frame: CallFrame): GasProfileFrame {
+  const calls = (frame.calls ?? []).map(computeGasProfile);
+
+  const gasUsed = BigInt(frame.gasUsed);
+  let selfGasUsed = gasUsed;
+  for (const call of calls) {
+    selfGasUsed -= BigInt(call.gasUsed);
+  }
+
+  // The gas used by each call depends on the cost of its last step, which
+  // may be wrong, so the gas of the calls can exceed the gas of the frame
+  if (selfGasUsed < 0n) {
+    selfGasUsed = 0n;
+  }
+
+  const profile: GasProfileFrame = {
+    type: frame.type,
+    gasUsed: frame.gasUsed,
+    selfGasUsed: numberToRpcQuantity(selfGasUsed),
+  };
+
+  if (frame.to !== undefined) {
+    profile.address = frame.to;
+  }
+
+  const isCreate = frame.type === "CREATE" || frame.type === "CREATE2";
+  if (!isCreate && frame.input.length >= 2 + SELECTOR_LENGTH * 2) {
+    profile.selector = frame.input.slice(0, 2 + SELECTOR_LENGTH * 2);
+  }
+
+  if (calls.length > 0) {
+    profile.calls = calls;
+  }
+
+  return profile;
+}
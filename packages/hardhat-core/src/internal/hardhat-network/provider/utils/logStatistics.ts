@@ -0,0 +1,42 @@
+interface RpcLog {
+  address: string;
+  topics: string[];
+}
+
+export interface AddressLogStatistics {
+  totalLogs: number;
+  // Number of logs per first topic, which is the event selector of
+  // non-anonymous events. Logs without topics are only counted in totalLogs.
+  topics: { [topic0: string]: number };
+}
+
+export interface LogStatistics {
+  totalLogs: number;
+  addresses: { [address: string]: AddressLogStatistics };
+}
+
+/**
+ * Counts the logs returned by `eth_getLogs` per address and first topic.
+ */
+export function computeLogStatistics(logs: RpcLog[]): LogStatistics {
+  const statistics: LogStatistics = { totalLogs: 0, addresses: {} };
+
+  for (const log of logs) {
+    const address = log.address.toLowerCase();
+    const addressStatistics = (statistics.addresses[address] ??= {
+      totalLogs: 0,
+      topics: {},
+    });
+
+    statistics.totalLogs += 1;
+    addressStatistics.totalLogs += 1;
+
+    if (log.topics.length > 0) {
+      const topic0 = log.topics[0].toLowerCase();
+      addressStatistics.topics[topic0] =
+        (addressStatistics.topics[topic0] ?? 0) + 1;
+    }
+  }
+
+  return statistics;
+}
@@ -9,12 +9,16 @@ export function makeCommon({ chainId, networkId, hardfork }: LocalNodeConfig) {
       chainId,
       networkId,
     },
-    {
-      // ethereumjs uses this name for the merge hardfork
-      hardfork:
-        hardfork === HardforkName.MERGE ? "mergeForkIdTransition" : hardfork,
-    }
+    { hardfork: getEthereumJsHardfork(hardfork) }
   );
 
   return common;
 }
+
+/**
+ * Returns the name that ethereumjs uses for a Hardhat Network hardfork.
+ */
+export function getEthereumJsHardfork(hardfork: string): string {
+  // ethereumjs uses this name for the merge hardfork
+  return hardfork === HardforkName.MERGE ? "mergeForkIdTransition" : hardfork;
+}
@@ -0,0 +1,76 @@
+import * as t from "io-ts";
+
+import { optionalOrNullable } from "../../../util/io-ts";
+import { ReturnData } from "../return-data";
+
+// The options of `hardhat_multicall`. If `trace` is true, the calls are
+// traced, which reports their failures even if `throwOnCallFailures` is
+// disabled.
+export const rpcMulticallOptions = t.type(
+  {
+    trace: optionalOrNullable(t.boolean),
+  },
+  "RpcMulticallOptions"
+);
+
+export const optionalRpcMulticallOptions =
+  optionalOrNullable(rpcMulticallOptions);
+
+export type OptionalRpcMulticallOptions = t.TypeOf<
+  typeof optionalRpcMulticallOptions
+>;
+
+export interface MulticallResult {
+  success: boolean;
+  returnData: string;
+  error?: string;
+}
+
+/**
+ * Builds the result of a `hardhat_multicall` call from the data that it
+ * returned, or its revert data if it failed.
+ */
+export function getMulticallResult(
+  returnData: string,
+  failed: boolean
+): MulticallResult {
+  if (!failed) {
+    return { success: true, returnData };
+  }
+
+  return {
+    success: false,
+    returnData,
+    error: getCallFailureMessage(Buffer.from(returnData.slice(2), "hex")),
+  };
+}
+
+/**
+ * Returns the return data of a failed `eth_call` from the error it threw, or
+ * `undefined` if the error wasn't caused by the call failing, like the one of
+ * an unknown block.
+ */
+export function getFailedCallReturnData(error: any): string | undefined {
+  const data = typeof error.data === "string" ? error.data : error.data?.data;
+
+  return typeof data === "string" ? data : undefined;
+}
+
+function getCallFailureMessage(returnData: Buffer): string {
+  const data = new ReturnData(returnData);
+
+  if (data.isEmpty()) {
+    return "Call failed without a reason";
+  }
+
+  if (data.isErrorReturnData()) {
+    return `Call reverted with reason string '${data.decodeError()}'`;
+  }
+
+  if (data.isPanicReturnData()) {
+    const code = data.decodePanic().toString(16);
+    return `Call reverted with panic code 0x${code}`;
+  }
+
+  return `Call reverted with custom error 0x${data.getSelector()}`;
+}
@@ -0,0 +1,38 @@
+import { RpcDebugTraceOutput } from "../output";
+
+export const OPCODE_STATS_TRACER = "opcodeStatsTracer";
+
+export interface OpcodeStatistics {
+  failed: boolean;
+  gas: number;
+  totalSteps: number;
+  opcodes: {
+    [op: string]: {
+      count: number;
+      gas: number;
+    };
+  };
+}
+
+/**
+ * Counts the steps of a trace, and the gas they used, per opcode.
+ */
+export function computeOpcodeStatistics(
+  trace: RpcDebugTraceOutput
+): OpcodeStatistics {
+  const statistics: OpcodeStatistics = {
+    failed: trace.failed,
+    gas: trace.gas,
+    totalSteps: trace.structLogs.length,
+    opcodes: {},
+  };
+
+  for (const { op, gasCost } of trace.structLogs) {
+    const opcodeStatistics = (statistics.opcodes[op] ??= { count: 0, gas: 0 });
+
+    opcodeStatistics.count += 1;
+    opcodeStatistics.gas += gasCost;
+  }
+
+  return statistics;
+}
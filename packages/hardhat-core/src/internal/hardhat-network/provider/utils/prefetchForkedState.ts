@@ -0,0 +1,123 @@
+import type {
+  EIP1193Provider,
+  HardhatNetworkForkingPrefetchConfig,
+} from "../../../../types";
+
+import debug from "debug";
+
+import { numberToRpcQuantity } from "../../../core/jsonrpc/types/base-types";
+
+const log = debug("hardhat:core:hardhat-network:prefetch");
+
+// Maximum number of requests that are sent to the remote node at once
+const PREFETCH_CONCURRENCY = 16;
+
+interface PrefetchRequest {
+  method: string;
+  params: unknown[];
+  // The request that stores the fetched value in the local node
+  toLocalRequest: (value: string) => { method: string; params: unknown[] };
+}
+
+/**
+ * Fetches the accounts and storage slots of `prefetch` at the fork block from
+ * the remote node, and stores them in the local node before the first
+ * request that needs them.
+ *
+ * The values are fetched with `remoteProvider`, because the local node
+ * handles one request at a time and would fetch them one by one. They are
+ * stored with the `hardhat_set*` methods, which keep the state unchanged as
+ * they set the same values the fork block has.
+ *
+ * Prefetching is only an optimization, so a value that can't be fetched is
+ * logged and skipped.
+ */
+export async function prefetchForkedState(
+  remoteProvider: EIP1193Provider,
+  localProvider: EIP1193Provider,
+  forkBlockTag: string,
+  prefetch: HardhatNetworkForkingPrefetchConfig[]
+): Promise<void> {
+  const requests: PrefetchRequest[] = [];
+
+  for (const { address, storageRanges = [] } of prefetch) {
+    requests.push(
+      {
+        method: "eth_getCode",
+        params: [address, forkBlockTag],
+        toLocalRequest: (code) => ({
+          method: "hardhat_setCode",
+          params: [address, code],
+        }),
+      },
+      {
+        method: "eth_getBalance",
+        params: [address, forkBlockTag],
+        toLocalRequest: (balance) => ({
+          method: "hardhat_setBalance",
+          params: [address, balance],
+        }),
+      },
+      {
+        method: "eth_getTransactionCount",
+        params: [address, forkBlockTag],
+        toLocalRequest: (nonce) => ({
+          method: "hardhat_setNonce",
+          params: [address, nonce],
+        }),
+      }
+    );
+
+    for (const { from, to } of storageRanges) {
+      for (let slot = from; slot <= to; slot++) {
+        const position = numberToRpcQuantity(slot);
+
+        requests.push({
+          method: "eth_getStorageAt",
+          params: [address, position, forkBlockTag],
+          toLocalRequest: (value) => ({
+            method: "hardhat_setStorageAt",
+            params: [
+              address,
+              position,
+              `0x${value.slice(2).padStart(64, "0")}`,
+            ],
+          }),
+        });
+      }
+    }
+  }
+
+  log(`Prefetching ${requests.length} values`);
+
+  const { default: pMap } = await import("p-map");
+  const values = await pMap(
+    requests,
+    async ({ method, params }) => {
+      try {
+        return (await remoteProvider.request({ method, params })) as string;
+      } catch (error) {
+        log(`Failed to prefetch ${method} %O: %O`, params, error);
+        return undefined;
+      }
+    },
+    { concurrency: PREFETCH_CONCURRENCY }
+  );
+
+  for (const [i, value] of values.entries()) {
+    if (value === undefined) {
+      continue;
+    }
+
+    const localRequest = requests[i].toLocalRequest(value);
+    try {
+      await localProvider.request(localRequest);
+    } catch (error) {
+      log(
+        `Failed to store prefetched value with ${localRequest.method} %O: %O`,
+        localRequest.params,
+        error
+      );
+    }
+  }
+}
@@ -0,0 +1,138 @@
+import type EthereumjsRlpT from "@nomicfoundation/ethereumjs-rlp";
+import type { Input } from "@nomicfoundation/ethereumjs-rlp";
+import type * as UtilKeccakT from "../../../util/keccak";
+
+import { bufferToRpcData } from "../../../core/jsonrpc/types/base-types";
+import { InternalError } from "../../../core/providers/errors";
+
+import {
+  dataToBytes,
+  encodeRpcTransaction,
+  quantityToBytes,
+} from "./rawTransaction";
+
+// Header fields that were added by hardforks, in the order they are encoded.
+// A block has all of them up to the last hardfork it was mined with.
+const OPTIONAL_HEADER_FIELDS = [
+  ["baseFeePerGas", quantityToBytes],
+  ["withdrawalsRoot", dataToBytes],
+  ["blobGasUsed", quantityToBytes],
+  ["excessBlobGas", quantityToBytes],
+  ["parentBeaconBlockRoot", dataToBytes],
+] as const;
+
+/**
+ * Returns the RLP-encoded form of a block returned by `eth_getBlockByNumber`
+ * or `eth_getBlockByHash` with its full transactions.
+ *
+ * Blocks with uncles can't be encoded, as only the hashes of the uncles are
+ * returned by those methods.
+ */
+export function encodeRpcBlock(block: any): string {
+  const { RLP } =
+    require("@nomicfoundation/ethereumjs-rlp") as typeof EthereumjsRlpT;
+  const { keccak256 } = require("../../../util/keccak") as typeof UtilKeccakT;
+
+  if (block.uncles.length > 0) {
+    // eslint-disable-next-line @nomicfoundation/hardhat-internal-rules/only-hardhat-error
+    throw new InternalError(
+      `Block ${block.hash} couldn't be encoded: blocks with uncles aren't supported`
+    );
+  }
+
+  const header = getHeaderFields(block);
+
+  // A mismatch means that some field wasn't encoded as it was hashed
+  if (
+    bufferToRpcData(keccak256(RLP.encode(header))) !== block.hash.toLowerCase()
+  ) {
+    // eslint-disable-next-line @nomicfoundation/hardhat-internal-rules/only-hardhat-error
+    throw new InternalError(
+      `Block ${block.hash} couldn't be encoded: the hash of the encoded header doesn't match`
+    );
+  }
+
+  // Legacy transactions are embedded as lists, and typed transactions as the
+  // bytes of their serialized form
+  const transactions = (block.transactions as any[]).map((tx) => {
+    const serialized = dataToBytes(encodeRpcTransaction(tx));
+    return Number(tx.type ?? "0x0") === 0 ? RLP.decode(serialized) : serialized;
+  });
+
+  const fields: Input[] = [header, transactions, []];
+
+  if (block.withdrawals !== undefined) {
+    fields.push(
+      (block.withdrawals as any[]).map((withdrawal) => [
+        quantityToBytes(withdrawal.index),
+        quantityToBytes(withdrawal.validatorIndex),
+        dataToBytes(withdrawal.address),
+        quantityToBytes(withdrawal.amount),
+      ])
+    );
+  }
+
+  return bufferToRpcData(RLP.encode(fields));
+}
+
+/**
+ * Returns the consensus encoding of each receipt returned by
+ * `eth_getTransactionReceipt`, as they are stored in the receipts trie.
+ */
+export function encodeRpcReceipts(receipts: any[]): string[] {
+  const { RLP } =
+    require("@nomicfoundation/ethereumjs-rlp") as typeof EthereumjsRlpT;
+
+  return receipts.map((receipt) => {
+    const encoded = RLP.encode([
+      // Receipts from before Byzantium have a state root instead of a status
+      receipt.root !== undefined
+        ? dataToBytes(receipt.root)
+        : quantityToBytes(receipt.status),
+      quantityToBytes(receipt.cumulativeGasUsed),
+      dataToBytes(receipt.logsBloom),
+      (receipt.logs as any[]).map((log) => [
+        dataToBytes(log.address),
+        (log.topics as string[]).map(dataToBytes),
+        dataToBytes(log.data),
+      ]),
+    ]);
+
+    const type = Number(receipt.type ?? "0x0");
+    if (type === 0) {
+      return bufferToRpcData(encoded);
+    }
+
+    return bufferToRpcData(Buffer.concat([Buffer.from([type]), encoded]));
+  });
+}
+
+function getHeaderFields(block: any): Uint8Array[] {
+  const fields = [
+    dataToBytes(block.parentHash),
+    dataToBytes(block.sha3Uncles),
+    dataToBytes(block.miner),
+    dataToBytes(block.stateRoot),
+    dataToBytes(block.transactionsRoot),
+    dataToBytes(block.receiptsRoot),
+    dataToBytes(block.logsBloom),
+    quantityToBytes(block.difficulty),
+    quantityToBytes(block.number),
+    quantityToBytes(block.gasLimit),
+    quantityToBytes(block.gasUsed),
+    quantityToBytes(block.timestamp),
+    dataToBytes(block.extraData),
+    dataToBytes(block.mixHash),
+    dataToBytes(block.nonce),
+  ];
+
+  for (const [name, encode] of OPTIONAL_HEADER_FIELDS) {
+    if (block[name] === undefined || block[name] === null) {
+      break;
+    }
+
+    fields.push(encode(block[name]));
+  }
+
+  return fields;
+}
@@ -0,0 +1,150 @@
+import type EthereumjsCommonT from "@nomicfoundation/ethereumjs-common";
+import type { Common } from "@nomicfoundation/ethereumjs-common";
+import type EthereumjsRlpT from "@nomicfoundation/ethereumjs-rlp";
+import type EthereumjsTxT from "@nomicfoundation/ethereumjs-tx";
+import type EthereumjsUtilT from "@nomicfoundation/ethereumjs-util";
+import type * as UtilKeccakT from "../../../util/keccak";
+
+import { bufferToRpcData } from "../../../core/jsonrpc/types/base-types";
+import { InternalError } from "../../../core/providers/errors";
+
+const BLOB_TRANSACTION_TYPE = 3;
+
+/**
+ * Returns the signed, serialized form of a transaction returned by
+ * `eth_getTransactionByHash`.
+ */
+export function encodeRpcTransaction(tx: any): string {
+  const { keccak256 } = require("../../../util/keccak") as typeof UtilKeccakT;
+
+  // Blob transactions are encoded here, as the transactions library can't
+  // create them without a KZG setup
+  const serialized =
+    Number(tx.type ?? "0x0") === BLOB_TRANSACTION_TYPE
+      ? encodeBlobTransaction(tx)
+      : serializeTransaction(tx);
+
+  // A mismatch means that some field wasn't encoded as it was signed
+  if (bufferToRpcData(keccak256(serialized)) !== tx.hash.toLowerCase()) {
+    // eslint-disable-next-line @nomicfoundation/hardhat-internal-rules/only-hardhat-error
+    throw new InternalError(
+      `Transaction ${tx.hash} couldn't be encoded: the hash of the encoded transaction doesn't match`
+    );
+  }
+
+  return bufferToRpcData(serialized);
+}
+
+/**
+ * Returns the RLP encoding of a quantity of a JSON-RPC response.
+ */
+export function quantityToBytes(value: string): Uint8Array {
+  const { bigIntToUnpaddedBytes } =
+    require("@nomicfoundation/ethereumjs-util") as typeof EthereumjsUtilT;
+
+  return bigIntToUnpaddedBytes(BigInt(value));
+}
+
+/**
+ * Returns the bytes of a data field of a JSON-RPC response.
+ */
+export function dataToBytes(value: string): Uint8Array {
+  const { hexToBytes } =
+    require("@nomicfoundation/ethereumjs-util") as typeof EthereumjsUtilT;
+
+  return hexToBytes(value);
+}
+
+function serializeTransaction(tx: any): Buffer {
+  const { TransactionFactory } =
+    require("@nomicfoundation/ethereumjs-tx") as typeof EthereumjsTxT;
+
+  const type = Number(tx.type ?? "0x0");
+
+  const txData = {
+    type,
+    nonce: tx.nonce,
+    gasLimit: tx.gas,
+    to: tx.to ?? undefined,
+    value: tx.value,
+    data: tx.input,
+    v: tx.v,
+    r: tx.r,
+    s: tx.s,
+    ...(type === 0 ? {} : { chainId: tx.chainId, accessList: tx.accessList }),
+    ...(type < 2
+      ? { gasPrice: tx.gasPrice }
+      : {
+          maxFeePerGas: tx.maxFeePerGas,
+          maxPriorityFeePerGas: tx.maxPriorityFeePerGas,
+        }),
+  };
+
+  try {
+    return Buffer.from(
+      TransactionFactory.fromTxData(txData, {
+        common: commonForTransaction(tx),
+      }).serialize()
+    );
+  } catch (error: any) {
+    // eslint-disable-next-line @nomicfoundation/hardhat-internal-rules/only-hardhat-error
+    throw new InternalError(
+      `Transaction ${tx.hash} couldn't be encoded: ${error.message}`,
+      error
+    );
+  }
+}
+
+// Blob transactions can't create contracts, so they always have a `to`
+function encodeBlobTransaction(tx: any): Buffer {
+  const { RLP } =
+    require("@nomicfoundation/ethereumjs-rlp") as typeof EthereumjsRlpT;
+
+  const fields = [
+    quantityToBytes(tx.chainId),
+    quantityToBytes(tx.nonce),
+    quantityToBytes(tx.maxPriorityFeePerGas),
+    quantityToBytes(tx.maxFeePerGas),
+    quantityToBytes(tx.gas),
+    dataToBytes(tx.to),
+    quantityToBytes(tx.value),
+    dataToBytes(tx.input),
+    (tx.accessList as any[]).map((item) => [
+      dataToBytes(item.address),
+      (item.storageKeys as string[]).map(dataToBytes),
+    ]),
+    quantityToBytes(tx.maxFeePerBlobGas),
+    (tx.blobVersionedHashes as string[]).map(dataToBytes),
+    quantityToBytes(tx.yParity ?? tx.v),
+    quantityToBytes(tx.r),
+    quantityToBytes(tx.s),
+  ];
+
+  return Buffer.concat([
+    Buffer.from([BLOB_TRANSACTION_TYPE]),
+    RLP.encode(fields),
+  ]);
+}
+
+function commonForTransaction(tx: any): Common {
+  const { Common: CommonClass } =
+    require("@nomicfoundation/ethereumjs-common") as typeof EthereumjsCommonT;
+
+  let chainId: bigint | undefined;
+  if (tx.chainId !== undefined && tx.chainId !== null) {
+    chainId = BigInt(tx.chainId);
+  } else if (BigInt(tx.v) >= 35n) {
+    // EIP-155 signatures encode the chain id in v
+    chainId = (BigInt(tx.v) - 35n) / 2n;
+  }
+
+  // Transactions signed without a chain id are valid in any chain
+  if (chainId === undefined) {
+    return new CommonClass({ chain: "mainnet" });
+  }
+
+  return CommonClass.custom({
+    chainId: Number(chainId),
+    networkId: Number(chainId),
+  });
+}
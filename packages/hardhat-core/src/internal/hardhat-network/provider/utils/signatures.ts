@@ -0,0 +1,62 @@
+import type EthSigUtilT from "@metamask/eth-sig-util";
+
+import { bufferToRpcData } from "../../../core/jsonrpc/types/base-types";
+import { InvalidArgumentsError } from "../../../core/providers/errors";
+
+/**
+ * Returns the address that signed `message` with an EIP-191 prefixed
+ * signature, like the ones of `personal_sign` and `eth_sign`.
+ */
+export function recoverMessageSigner(
+  message: Buffer,
+  signature: Buffer
+): string {
+  const { recoverPersonalSignature } =
+    require("@metamask/eth-sig-util") as typeof EthSigUtilT;
+
+  try {
+    return recoverPersonalSignature({
+      data: bufferToRpcData(message),
+      signature: bufferToRpcData(signature),
+    });
+  } catch (error: any) {
+    // eslint-disable-next-line @nomicfoundation/hardhat-internal-rules/only-hardhat-error
+    throw new InvalidArgumentsError(
+      `Invalid signature: ${error.message}`,
+      error
+    );
+  }
+}
+
+/**
+ * Checks if `signature` is an EIP-712 signature of `typedData` by `address`,
+ * like the ones of `eth_signTypedData_v4`. The typed data can be an object or
+ * its JSON representation.
+ */
+export function verifyTypedDataSignature(
+  address: Buffer,
+  typedData: unknown,
+  signature: Buffer
+): boolean {
+  const { recoverTypedSignature, SignTypedDataVersion } =
+    require("@metamask/eth-sig-util") as typeof EthSigUtilT;
+
+  let signer: string;
+  try {
+    signer = recoverTypedSignature({
+      data: (typeof typedData === "string"
+        ? JSON.parse(typedData)
+        : typedData) as any,
+      signature: bufferToRpcData(signature),
+      version: SignTypedDataVersion.V4,
+    });
+  } catch (error: any) {
+    // eslint-disable-next-line @nomicfoundation/hardhat-internal-rules/only-hardhat-error
+    throw new InvalidArgumentsError(
+      `Invalid typed data or signature: ${error.message}`,
+      error
+    );
+  }
+
+  return signer.toLowerCase() === bufferToRpcData(address);
+}
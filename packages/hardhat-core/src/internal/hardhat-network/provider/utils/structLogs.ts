@@ -0,0 +1,29 @@
+/**
+ * Returns the n-th item from the top of the stack of a struct log.
+ */
+export function stackItem(stack: string[], n: number): bigint {
+  const item = stack[stack.length - 1 - n] ?? "0";
+  return BigInt(`0x${strip0x(item)}`);
+}
+
+/**
+ * Reads a slice of the memory of a struct log, as a hex string.
+ */
+export function readMemory(
+  memory: string[],
+  offset: number,
+  size: number
+): string {
+  // Memory is expanded with zeros when it's read past its end
+  const bytes = memory
+    .map(strip0x)
+    .join("")
+    .slice(offset * 2, (offset + size) * 2)
+    .padEnd(size * 2, "0");
+
+  return `0x${bytes}`;
+}
+
+function strip0x(hex: string): string {
+  return hex.startsWith("0x") ? hex.slice(2) : hex;
+}
@@ -0,0 +1,101 @@
+import { once } from "events";
+import fsExtra from "fs-extra";
+import os from "os";
+import path from "path";
+
+import { getTracingConfigParamIndex } from "./tracingConfig";
+
+/**
+ * Checks if a `debug_trace*` request has the `exportToFile` option enabled.
+ * It returns the params without that option, which EDR doesn't know about,
+ * and whether it was enabled.
+ */
+export function extractTraceExportOption(
+  method: string,
+  params: any[]
+): [any[], boolean] {
+  const index = getTracingConfigParamIndex(method);
+  if (index === undefined) {
+    return [params, false];
+  }
+
+  const config = params[index];
+  if (
+    typeof config !== "object" ||
+    config === null ||
+    !("exportToFile" in config)
+  ) {
+    return [params, false];
+  }
+
+  const { exportToFile, ...configWithoutExport } = config;
+
+  const newParams = [...params];
+  newParams[index] = configWithoutExport;
+
+  return [newParams, exportToFile === true];
+}
+
+// Only the most recent exported traces are kept, so that a long-running node
+// doesn't fill the disk
+export const MAX_EXPORTED_TRACES = 20;
+
+let exportDirectory: Promise<string> | undefined;
+let exportedTracesCount = 0;
+const exportedFiles: string[] = [];
+
+/**
+ * Writes a trace to a new JSONL file in a temporary directory of this
+ * process, and returns its path. Each struct log is written in its own line,
+ * followed by a line with the rest of the trace's fields.
+ *
+ * The directory is deleted when the process exits, and only the last
+ * `MAX_EXPORTED_TRACES` files are kept in it.
+ */
+export async function writeTraceToFile(trace: object): Promise<string> {
+  const directory = await getExportDirectory();
+  const file = path.join(directory, `trace-${exportedTracesCount++}.jsonl`);
+  exportedFiles.push(file);
+
+  const stream = fsExtra.createWriteStream(file);
+
+  const writeLine = async (value: unknown) => {
+    if (!stream.write(`${JSON.stringify(value)}\n`)) {
+      await once(stream, "drain");
+    }
+  };
+
+  const { structLogs, ...summary } = trace as { structLogs?: unknown };
+
+  if (Array.isArray(structLogs)) {
+    for (const structLog of structLogs) {
+      await writeLine(structLog);
+    }
+  }
+
+  await writeLine(summary);
+
+  stream.end();
+  await once(stream, "finish");
+
+  const removedFiles = exportedFiles.splice(
+    0,
+    Math.max(0, exportedFiles.length - MAX_EXPORTED_TRACES)
+  );
+  await Promise.all(removedFiles.map((f) => fsExtra.remove(f)));
+
+  return file;
+}
+
+function getExportDirectory(): Promise<string> {
+  if (exportDirectory === undefined) {
+    exportDirectory = fsExtra
+      .mkdtemp(path.join(os.tmpdir(), "hardhat-traces-"))
+      .then((directory) => {
+        process.once("exit", () => fsExtra.removeSync(directory));
+        return directory;
+      });
+  }
+
+  return exportDirectory;
+}
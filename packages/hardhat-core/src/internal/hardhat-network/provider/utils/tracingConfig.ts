@@ -0,0 +1,46 @@
+// Position of the tracing config param of each method that has one. The
+// block methods pass theirs on to debug_traceTransaction.
+const TRACING_CONFIG_PARAM_INDEX: { [method: string]: number } = {
+  debug_traceBlockByHash: 1,
+  debug_traceBlockByNumber: 1,
+  debug_traceCall: 2,
+  debug_traceTransaction: 1,
+};
+
+/**
+ * Returns the position of the tracing config param of a `debug_trace*`
+ * method, or `undefined` if the method doesn't take one.
+ */
+export function getTracingConfigParamIndex(method: string): number | undefined {
+  return TRACING_CONFIG_PARAM_INDEX[method];
+}
+
+/**
+ * Checks if a `debug_trace*` request asks for the tracer `name`, which is
+ * computed from the struct logs of a plain trace. If it does, it returns the
+ * params of that trace and `true`. The trace only has the memory and the stack
+ * if the tracer reads them.
+ */
+export function extractTracer(
+  method: string,
+  params: any[],
+  name: string,
+  readsMemoryAndStack: boolean
+): [any[], boolean] {
+  const index = getTracingConfigParamIndex(method);
+  if (index === undefined || params[index]?.tracer !== name) {
+    return [params, false];
+  }
+
+  const { tracer: _tracer, ...config } = params[index];
+
+  const newParams = [...params];
+  newParams[index] = {
+    ...config,
+    disableMemory: !readsMemoryAndStack,
+    disableStack: !readsMemoryAndStack,
+    disableStorage: true,
+  };
+
+  return [newParams, true];
+}
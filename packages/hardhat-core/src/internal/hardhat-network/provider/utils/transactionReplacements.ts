@@ -0,0 +1,67 @@
+/**
+ * Keeps track of the transactions sent to the mempool by sender and nonce, to
+ * know which ones were replaced by a later transaction with the same sender
+ * and nonce.
+ */
+export class TransactionReplacements {
+  // Hash of the last transaction sent with each sender and nonce
+  private readonly _sentTransactions = new Map<string, string>();
+  // Hash of the transaction that replaced each replaced transaction
+  private readonly _replacements = new Map<string, string>();
+
+  /**
+   * Records a transaction that was added to the mempool. If it replaces a
+   * previous transaction, it returns the hash of the replaced one.
+   */
+  public addTransaction(
+    sender: string,
+    nonce: bigint,
+    txHash: string
+  ): string | undefined {
+    const key = `${sender.toLowerCase()}:${nonce}`;
+    const hash = txHash.toLowerCase();
+
+    const previousHash = this._sentTransactions.get(key);
+    this._sentTransactions.set(key, hash);
+
+    if (previousHash === undefined || previousHash === hash) {
+      return undefined;
+    }
+
+    this._replacements.set(previousHash, hash);
+
+    return previousHash;
+  }
+
+  /**
+   * Forgets a transaction that was removed from the mempool without being
+   * replaced, so that sending another one with its nonce isn't considered a
+   * replacement.
+   */
+  public removeTransaction(txHash: string) {
+    const hash = txHash.toLowerCase();
+
+    for (const [key, sentHash] of this._sentTransactions) {
+      if (sentHash === hash) {
+        this._sentTransactions.delete(key);
+      }
+    }
+  }
+
+  public getReplacement(txHash: string): string | undefined {
+    return this._replacements.get(txHash.toLowerCase());
+  }
+
+  /**
+   * Forgets the sent transactions, but not the replacements that already
+   * happened.
+   */
+  public clearSentTransactions() {
+    this._sentTransactions.clear();
+  }
+
+  public clear() {
+    this._sentTransactions.clear();
+    this._replacements.clear();
+  }
+}
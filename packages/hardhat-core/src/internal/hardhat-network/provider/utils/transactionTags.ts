@@ -0,0 +1,124 @@
+// Methods whose result is a single transaction or receipt
+const TRANSACTION_RESULT_METHODS = new Set([
+  "eth_getTransactionByBlockHashAndIndex",
+  "eth_getTransactionByBlockNumberAndIndex",
+  "eth_getTransactionByHash",
+  "eth_getTransactionReceipt",
+]);
+
+// Methods whose result is a block, possibly with full transactions
+const BLOCK_RESULT_METHODS = new Set([
+  "eth_getBlockByHash",
+  "eth_getBlockByNumber",
+]);
+
+/**
+ * Keeps the opaque tags that clients attach to the transactions they send,
+ * and adds them to the transactions, receipts and traces returned for those
+ * transactions.
+ */
+export class TransactionTags {
+  private readonly _tags = new Map<string, unknown>();
+  // Tagged transactions that were sent, but whose hash isn't known yet
+  private readonly _pendingTags = new Set<Promise<void>>();
+
+  /**
+   * Removes the `tag` field from the transaction object of an
+   * `eth_sendTransaction` request, returning the new params and the tag.
+   */
+  public extractTag(params: any[]): [any[], unknown] {
+    const [tx, ...rest] = params;
+
+    if (typeof tx !== "object" || tx === null || !("tag" in tx)) {
+      return [params, undefined];
+    }
+
+    const { tag, ...txWithoutTag } = tx;
+
+    return [[txWithoutTag, ...rest], tag];
+  }
+
+  /**
+   * Registers a tagged transaction that is being sent. The returned function
+   * must be called once its tag was set, or if sending it failed.
+   */
+  public startTagging(): () => void {
+    let resolve!: () => void;
+    const promise = new Promise<void>((res) => {
+      resolve = res;
+    });
+
+    this._pendingTags.add(promise);
+
+    return () => {
+      this._pendingTags.delete(promise);
+      resolve();
+    };
+  }
+
+  /**
+   * Waits until the tags of the transactions that are being sent are set.
+   */
+  public async waitForPendingTags() {
+    await Promise.all(this._pendingTags);
+  }
+
+  public setTag(txHash: string, tag: unknown) {
+    if (tag === undefined) {
+      this._tags.delete(txHash.toLowerCase());
+    } else {
+      this._tags.set(txHash.toLowerCase(), tag);
+    }
+  }
+
+  public getTag(txHash: string): unknown {
+    return this._tags.get(txHash.toLowerCase());
+  }
+
+  public clear() {
+    this._tags.clear();
+  }
+
+  public addTagsToResult(method: string, params: any[], result: any): any {
+    if (this._tags.size === 0 || result === null || result === undefined) {
+      return result;
+    }
+
+    if (TRANSACTION_RESULT_METHODS.has(method)) {
+      return this._addTag(result);
+    }
+
+    if (method === "eth_pendingTransactions" && Array.isArray(result)) {
+      return result.map((tx) => this._addTag(tx));
+    }
+
+    if (
+      BLOCK_RESULT_METHODS.has(method) &&
+      Array.isArray(result.transactions)
+    ) {
+      return {
+        ...result,
+        transactions: result.transactions.map((tx: unknown) =>
+          tx !== null && typeof tx === "object" ? this._addTag(tx) : tx
+        ),
+      };
+    }
+
+    if (method === "debug_traceTransaction" && typeof params[0] === "string") {
+      const tag = this.getTag(params[0]);
+      return tag !== undefined ? { ...result, tag } : result;
+    }
+
+    return result;
+  }
+
+  private _addTag(txOrReceipt: any): any {
+    const hash = txOrReceipt.transactionHash ?? txOrReceipt.hash;
+    if (typeof hash !== "string") {
+      return txOrReceipt;
+    }
+
+    const tag = this.getTag(hash);
+    return tag !== undefined ? { ...txOrReceipt, tag } : txOrReceipt;
+  }
+}
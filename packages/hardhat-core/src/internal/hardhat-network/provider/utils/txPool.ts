@@ -0,0 +1,134 @@
+import {
+  numberToRpcQuantity,
+  rpcQuantityToBigInt,
+} from "../../../core/jsonrpc/types/base-types";
+import { fromEntries } from "../../../util/lang";
+
+interface RpcPendingTransaction {
+  from: string;
+  to: string | null;
+  nonce: string;
+  value: string;
+  gas: string;
+  gasPrice?: string;
+  maxFeePerGas?: string;
+}
+
+// Transactions of each sender, indexed by their nonces as decimal strings
+export interface TransactionsBySender<T> {
+  [sender: string]: { [nonce: string]: T };
+}
+
+export interface TxPoolContent<T> {
+  // Transactions that can be mined in the next block
+  pending: TransactionsBySender<T>;
+  // Transactions whose nonces are ahead of their senders' nonces
+  queued: TransactionsBySender<T>;
+}
+
+export interface TxPoolStatus {
+  pending: string;
+  queued: string;
+}
+
+/**
+ * Splits the mempool's transactions in pending and queued, like geth's
+ * `txpool_content` does. The transactions of a sender are pending if their
+ * nonces follow the sender's nonce without gaps.
+ *
+ * @param transactions The result of `eth_pendingTransactions`.
+ * @param getNonce Returns the nonce of a sender in the latest block.
+ */
+export async function getTxPoolContent<T extends RpcPendingTransaction>(
+  transactions: T[],
+  getNonce: (sender: string) => Promise<bigint>
+): Promise<TxPoolContent<T>> {
+  const content: TxPoolContent<T> = { pending: {}, queued: {} };
+
+  const transactionsBySender = new Map<string, T[]>();
+  for (const tx of transactions) {
+    const senderTransactions = transactionsBySender.get(tx.from) ?? [];
+    senderTransactions.push(tx);
+    transactionsBySender.set(tx.from, senderTransactions);
+  }
+
+  for (const [sender, senderTransactions] of transactionsBySender) {
+    let nextNonce = await getNonce(sender);
+
+    const sortedTransactions = [...senderTransactions].sort((a, b) =>
+      Number(rpcQuantityToBigInt(a.nonce) - rpcQuantityToBigInt(b.nonce))
+    );
+
+    for (const tx of sortedTransactions) {
+      const nonce = rpcQuantityToBigInt(tx.nonce);
+
+      let group: TransactionsBySender<T>;
+      if (nonce === nextNonce) {
+        group = content.pending;
+        nextNonce += 1n;
+      } else {
+        group = content.queued;
+      }
+
+      group[sender] ??= {};
+      group[sender][nonce.toString()] = tx;
+    }
+  }
+
+  return content;
+}
+
+/**
+ * Replaces each transaction of the content with a summary, in the format of
+ * geth's `txpool_inspect`.
+ */
+export function inspectTxPoolContent(
+  content: TxPoolContent<RpcPendingTransaction>
+): TxPoolContent<string> {
+  return {
+    pending: mapTransactions(content.pending, inspectTransaction),
+    queued: mapTransactions(content.queued, inspectTransaction),
+  };
+}
+
+/**
+ * Counts the pending and queued transactions, like geth's `txpool_status`.
+ */
+export function getTxPoolStatus(content: TxPoolContent<unknown>): TxPoolStatus {
+  return {
+    pending: numberToRpcQuantity(countTransactions(content.pending)),
+    queued: numberToRpcQuantity(countTransactions(content.queued)),
+  };
+}
+
+function mapTransactions<T, U>(
+  transactionsBySender: TransactionsBySender<T>,
+  f: (tx: T) => U
+): TransactionsBySender<U> {
+  return fromEntries(
+    Object.entries(transactionsBySender).map(([sender, transactions]) => [
+      sender,
+      fromEntries(
+        Object.entries(transactions).map(([nonce, tx]) => [nonce, f(tx)])
+      ),
+    ])
+  );
+}
+
+function countTransactions(
+  transactionsBySender: TransactionsBySender<unknown>
+): number {
+  return Object.values(transactionsBySender).reduce(
+    (count, transactions) => count + Object.keys(transactions).length,
+    0
+  );
+}
+
+function inspectTransaction(tx: RpcPendingTransaction): string {
+  const to = tx.to ?? "contract creation";
+  const value = rpcQuantityToBigInt(tx.value);
+  const gas = rpcQuantityToBigInt(tx.gas);
+  const gasPrice = rpcQuantityToBigInt(tx.gasPrice ?? tx.maxFeePerGas ?? "0x0");
+
+  return `${to}: ${value} wei + ${gas} gas × ${gasPrice} wei`;
+}
@@ -0,0 +1,268 @@
+import type { Common } from "@nomicfoundation/ethereumjs-common";
+import type EthereumjsTxT from "@nomicfoundation/ethereumjs-tx";
+import type { TypedTransaction } from "@nomicfoundation/ethereumjs-tx";
+import type { EIP1193Provider } from "../../../../types";
+
+import {
+  bufferToRpcData,
+  numberToRpcQuantity,
+  rpcQuantityToBigInt,
+} from "../../../core/jsonrpc/types/base-types";
+
+export interface RawTransactionVerdict {
+  // Whether the node would add the transaction to its mempool. A transaction
+  // can be accepted and still revert.
+  accepted: boolean;
+  // Whether the transaction would wait in the mempool for the transactions
+  // with the nonces before its own
+  queued?: boolean;
+  // The hash of the pending transaction with the same sender and nonce that
+  // the transaction would replace
+  replaces?: string;
+  errors: string[];
+  hash?: string;
+  from?: string;
+  gasEstimate?: string;
+  revert?: {
+    message: string;
+    data?: unknown;
+  };
+}
+
+/**
+ * Runs the checks that a signed transaction has to pass to be added to the
+ * mempool, and estimates its execution against the pending block, without
+ * sending it.
+ *
+ * With automine enabled, it also runs the checks that the transaction has to
+ * pass to be mined right away, like the node does.
+ *
+ * The `minGasPrice` of the mempool only applies before London, like in the
+ * node.
+ */
+export async function validateRawTransaction(
+  provider: EIP1193Provider,
+  common: Common,
+  minGasPrice: bigint,
+  rawTransaction: Buffer
+): Promise<RawTransactionVerdict> {
+  const { TransactionFactory } =
+    require("@nomicfoundation/ethereumjs-tx") as typeof EthereumjsTxT;
+
+  let tx: TypedTransaction;
+  let from: string;
+  try {
+    tx = TransactionFactory.fromSerializedData(rawTransaction, { common });
+    from = tx.getSenderAddress().toString();
+  } catch (error: any) {
+    return {
+      accepted: false,
+      errors: [`Invalid transaction: ${error.message}`],
+    };
+  }
+
+  const hash = bufferToRpcData(tx.hash());
+  const errors: string[] = [];
+
+  // The mempool checks the balance against the latest state, not against
+  // the one after the pending transactions
+  const [
+    knownTx,
+    rpcLatestNonce,
+    rpcPendingNonce,
+    balance,
+    pendingBlock,
+    automine,
+  ] = await Promise.all([
+    provider.request({ method: "eth_getTransactionByHash", params: [hash] }),
+    provider.request({
+      method: "eth_getTransactionCount",
+      params: [from, "latest"],
+    }),
+    provider.request({
+      method: "eth_getTransactionCount",
+      params: [from, "pending"],
+    }),
+    provider.request({ method: "eth_getBalance", params: [from, "latest"] }),
+    provider.request({
+      method: "eth_getBlockByNumber",
+      params: ["pending", false],
+    }),
+    provider.request({ method: "hardhat_getAutomine" }),
+  ]);
+
+  if (knownTx !== null) {
+    errors.push(`Known transaction: ${hash}`);
+  }
+
+  const maxFeePerGas = "maxFeePerGas" in tx ? tx.maxFeePerGas : tx.gasPrice;
+  const maxPriorityFeePerGas =
+    "maxPriorityFeePerGas" in tx ? tx.maxPriorityFeePerGas : tx.gasPrice;
+
+  // Nonces between the latest and the pending ones belong to transactions in
+  // the mempool, which can be replaced by paying more for their gas
+  const latestNonce = rpcQuantityToBigInt(rpcLatestNonce as string);
+  const pendingNonce = rpcQuantityToBigInt(rpcPendingNonce as string);
+  let replacedTx: any;
+  if (tx.nonce < latestNonce) {
+    errors.push(
+      `Nonce too low. Expected nonce to be at least ${latestNonce} but got ${tx.nonce}.`
+    );
+  } else if (tx.nonce < pendingNonce) {
+    replacedTx = await getPendingTransaction(provider, from, tx.nonce);
+  } else if (tx.nonce > pendingNonce && automine === true) {
+    errors.push(
+      `Nonce too high. Expected nonce to be ${pendingNonce} but got ${tx.nonce}. Note that transactions can't be queued when automining.`
+    );
+  }
+
+  if (replacedTx !== undefined && knownTx === null) {
+    const minMaxFeePerGas = getMinReplacementFee(
+      rpcQuantityToBigInt(replacedTx.maxFeePerGas ?? replacedTx.gasPrice)
+    );
+    const minMaxPriorityFeePerGas = getMinReplacementFee(
+      rpcQuantityToBigInt(
+        replacedTx.maxPriorityFeePerGas ?? replacedTx.gasPrice
+      )
+    );
+
+    if (maxFeePerGas < minMaxFeePerGas) {
+      errors.push(
+        `Replacement transaction underpriced. A gasPrice/maxFeePerGas of at least ${minMaxFeePerGas} is necessary to replace the existing transaction with nonce ${tx.nonce}.`
+      );
+    } else if (maxPriorityFeePerGas < minMaxPriorityFeePerGas) {
+      errors.push(
+        `Replacement transaction underpriced. A gasPrice/maxPriorityFeePerGas of at least ${minMaxPriorityFeePerGas} is necessary to replace the existing transaction with nonce ${tx.nonce}.`
+      );
+    }
+  }
+
+  if (
+    !common.gteHardfork("london") &&
+    "gasPrice" in tx &&
+    tx.gasPrice < minGasPrice
+  ) {
+    errors.push(
+      `Transaction gas price is ${tx.gasPrice}, which is below the minimum of ${minGasPrice}`
+    );
+  }
+
+  const intrinsicGas = tx.getBaseFee();
+  if (tx.gasLimit < intrinsicGas) {
+    errors.push(
+      `Transaction requires at least ${intrinsicGas} gas but got ${tx.gasLimit}`
+    );
+  }
+
+  const blockGasLimit = rpcQuantityToBigInt((pendingBlock as any).gasLimit);
+  if (tx.gasLimit > blockGasLimit) {
+    errors.push(
+      `Transaction gas limit is ${tx.gasLimit} and exceeds block gas limit of ${blockGasLimit}`
+    );
+  }
+
+  const { baseFeePerGas } = pendingBlock as { baseFeePerGas?: string };
+  if (automine === true && baseFeePerGas !== undefined) {
+    const nextBaseFee = rpcQuantityToBigInt(baseFeePerGas);
+    if (maxFeePerGas < nextBaseFee) {
+      const feeField = "maxFeePerGas" in tx ? "maxFeePerGas" : "gasPrice";
+      errors.push(
+        `Transaction ${feeField} (${maxFeePerGas}) is too low for the next block, which has a baseFeePerGas of ${nextBaseFee}`
+      );
+    }
+  }
+
+  const upfrontCost = tx.gasLimit * maxFeePerGas + tx.value;
+  const senderBalance = rpcQuantityToBigInt(balance as string);
+  if (senderBalance < upfrontCost) {
+    errors.push(
+      `Sender doesn't have enough funds to send tx. The max upfront cost is: ${upfrontCost} and the sender's account only has: ${senderBalance}`
+    );
+  }
+
+  const verdict: RawTransactionVerdict = {
+    accepted: errors.length === 0,
+    errors,
+    hash,
+    from,
+  };
+
+  if (verdict.accepted && tx.nonce > pendingNonce) {
+    verdict.queued = true;
+  }
+
+  if (replacedTx !== undefined) {
+    verdict.replaces = replacedTx.hash;
+  }
+
+  // Executing a transaction that the mempool would reject doesn't predict
+  // anything useful, and a queued transaction would run against a state that
+  // doesn't exist yet. So would a replacement, unless it replaces the next
+  // transaction of its sender, which runs on top of the latest block.
+  if (
+    !verdict.accepted ||
+    verdict.queued === true ||
+    (replacedTx !== undefined && tx.nonce !== latestNonce)
+  ) {
+    return verdict;
+  }
+
+  const feeFields =
+    "maxFeePerGas" in tx
+      ? {
+          maxFeePerGas: numberToRpcQuantity(tx.maxFeePerGas),
+          maxPriorityFeePerGas: numberToRpcQuantity(tx.maxPriorityFeePerGas),
+        }
+      : { gasPrice: numberToRpcQuantity(tx.gasPrice) };
+
+  // The nonce isn't sent because calls always use the sender's next nonce,
+  // which is the nonce of the transaction at this point
+  try {
+    verdict.gasEstimate = (await provider.request({
+      method: "eth_estimateGas",
+      params: [
+        {
+          from,
+          to: tx.to?.toString(),
+          value: numberToRpcQuantity(tx.value),
+          data: bufferToRpcData(tx.data),
+          gas: numberToRpcQuantity(tx.gasLimit),
+          ...feeFields,
+          accessList: "AccessListJSON" in tx ? tx.AccessListJSON : undefined,
+        },
+        replacedTx !== undefined ? "latest" : "pending",
+      ],
+    })) as string;
+  } catch (error: any) {
+    verdict.revert = {
+      message: error.message,
+      data: error.data,
+    };
+  }
+
+  return verdict;
+}
+
+async function getPendingTransaction(
+  provider: EIP1193Provider,
+  from: string,
+  nonce: bigint
+): Promise<any> {
+  const pendingTxs = (await provider.request({
+    method: "eth_pendingTransactions",
+  })) as any[];
+
+  return pendingTxs.find(
+    (pendingTx) =>
+      pendingTx.from.toLowerCase() === from &&
+      rpcQuantityToBigInt(pendingTx.nonce) === nonce
+  );
+}
+
+/**
+ * Returns the minimum fee that a replacement has to pay, which is 10% more
+ * than the one of the replaced transaction, rounded up.
+ */
+function getMinReplacementFee(fee: bigint): bigint {
+  return (fee * 110n + 99n) / 100n;
+}
@@ -58,6 +58,9 @@ export interface HardhatNetworkUserConfig {
   coinbase?: string;
   chains?: HardhatNetworkChainsUserConfig;
   enableTransientStorage?: boolean;
+  checksumAddresses?: boolean;
+  faucetRateLimit?: HardhatNetworkFaucetRateLimitConfig;
+  gasPriceOracle?: HardhatNetworkGasPriceOracleConfig;
 }
 
 export type HardhatNetworkAccountsUserConfig =
@@ -91,6 +94,13 @@ export interface HardhatNetworkForkingUserConfig {
   url: string;
   blockNumber?: number;
   httpHeaders?: { [name: string]: string };
+  prefetch?: HardhatNetworkForkingPrefetchConfig[];
+}
+
+export interface HardhatNetworkForkingPrefetchConfig {
+  address: string;
+  // Ranges of storage slots to prefetch, both ends included
+  storageRanges?: Array<{ from: number; to: number }>;
 }
 
 export type HttpNetworkAccountsUserConfig =
@@ -155,6 +165,26 @@ export interface HardhatNetworkConfig {
   chains: HardhatNetworkChainsConfig;
   allowBlocksWithSameTimestamp?: boolean;
   enableTransientStorage?: boolean;
+  checksumAddresses?: boolean;
+  faucetRateLimit?: HardhatNetworkFaucetRateLimitConfig;
+  gasPriceOracle?: HardhatNetworkGasPriceOracleConfig;
+}
+
+export interface HardhatNetworkFaucetRateLimitConfig {
+  // The maximum amount of wei that an address can get from
+  // `hardhat_requestFunds` within each interval
+  maxAmount: string;
+  // The length of the interval, in seconds
+  interval: number;
+}
+
+export interface HardhatNetworkGasPriceOracleConfig {
+  // How many of the latest blocks the suggested fees are based on
+  blocks: number;
+  // The percentile of the priority fees paid in each block that is used
+  percentile: number;
+  // The priority fee, in wei, that is suggested if those blocks are empty
+  fallbackMaxPriorityFeePerGas?: string;
 }
 
 export type HardhatNetworkAccountsConfig =
@@ -180,6 +210,7 @@ export interface HardhatNetworkForkingConfig {
   url: string;
   blockNumber?: number;
   httpHeaders?: { [name: string]: string };
+  prefetch?: HardhatNetworkForkingPrefetchConfig[];
 }
 
 export interface HttpNetworkConfig {
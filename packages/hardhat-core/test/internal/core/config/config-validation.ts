@@ -880,6 +880,196 @@ describe("Config validation", function () {
               }),
             ERRORS.GENERAL.INVALID_CONFIG
           );
+
+          expectHardhatError(
+            () =>
+              validateConfig({
+                networks: {
+                  [HARDHAT_NETWORK_NAME]: {
+                    forking: { url: "asd", prefetch: [{ address: "0x123" }] },
+                  },
+                },
+              }),
+            ERRORS.GENERAL.INVALID_CONFIG
+          );
+
+          expectHardhatError(
+            () =>
+              validateConfig({
+                networks: {
+                  [HARDHAT_NETWORK_NAME]: {
+                    forking: {
+                      url: "asd",
+                      prefetch: [
+                        {
+                          address: "0x0d2026b3EE6eC71FC6746ADb6311F6d3Ba1C000B",
+                          storageRanges: [{ from: "0" }],
+                        },
+                      ],
+                    },
+                  },
+                },
+              }),
+            ERRORS.GENERAL.INVALID_CONFIG
+          );
+
+          expectHardhatError(
+            () =>
+              validateConfig({
+                networks: {
+                  [HARDHAT_NETWORK_NAME]: {
+                    forking: {
+                      url: "asd",
+                      prefetch: [
+                        {
+                          address: "0x0d2026b3EE6eC71FC6746ADb6311F6d3Ba1C000B",
+                          storageRanges: [{ from: 1.5, to: 2 }],
+                        },
+                      ],
+                    },
+                  },
+                },
+              }),
+            ERRORS.GENERAL.INVALID_CONFIG
+          );
+
+          expectHardhatError(
+            () =>
+              validateConfig({
+                networks: {
+                  [HARDHAT_NETWORK_NAME]: {
+                    forking: {
+                      url: "asd",
+                      prefetch: [
+                        {
+                          address: "0x0d2026b3EE6eC71FC6746ADb6311F6d3Ba1C000B",
+                          storageRanges: [{ from: -1, to: 2 }],
+                        },
+                      ],
+                    },
+                  },
+                },
+              }),
+            ERRORS.GENERAL.INVALID_CONFIG
+          );
+
+          expectHardhatError(
+            () =>
+              validateConfig({
+                networks: {
+                  [HARDHAT_NETWORK_NAME]: {
+                    forking: {
+                      url: "asd",
+                      prefetch: [
+                        {
+                          address: "0x0d2026b3EE6eC71FC6746ADb6311F6d3Ba1C000B",
+                          storageRanges: [{ from: 10, to: 2 }],
+                        },
+                      ],
+                    },
+                  },
+                },
+              }),
+            ERRORS.GENERAL.INVALID_CONFIG
+          );
+
+          expectHardhatError(
+            () =>
+              validateConfig({
+                networks: {
+                  [HARDHAT_NETWORK_NAME]: {
+                    forking: {
+                      url: "asd",
+                      prefetch: [
+                        {
+                          address: "0x0d2026b3EE6eC71FC6746ADb6311F6d3Ba1C000B",
+                          storageRanges: [{ from: 0, to: 10_000 }],
+                        },
+                      ],
+                    },
+                  },
+                },
+              }),
+            ERRORS.GENERAL.INVALID_CONFIG
+          );
+        });
+
+        it("Should fail with invalid gas price oracle configs", function () {
+          expectHardhatError(
+            () =>
+              validateConfig({
+                networks: {
+                  [HARDHAT_NETWORK_NAME]: {
+                    gasPriceOracle: { blocks: 20 },
+                  },
+                },
+              }),
+            ERRORS.GENERAL.INVALID_CONFIG
+          );
+
+          expectHardhatError(
+            () =>
+              validateConfig({
+                networks: {
+                  [HARDHAT_NETWORK_NAME]: {
+                    gasPriceOracle: { blocks: 0, percentile: 60 },
+                  },
+                },
+              }),
+            ERRORS.GENERAL.INVALID_CONFIG
+          );
+
+          expectHardhatError(
+            () =>
+              validateConfig({
+                networks: {
+                  [HARDHAT_NETWORK_NAME]: {
+                    gasPriceOracle: { blocks: 20, percentile: 101 },
+                  },
+                },
+              }),
+            ERRORS.GENERAL.INVALID_CONFIG
+          );
+
+          expectHardhatError(
+            () =>
+              validateConfig({
+                networks: {
+                  [HARDHAT_NETWORK_NAME]: {
+                    gasPriceOracle: {
+                      blocks: 20,
+                      percentile: 60,
+                      fallbackMaxPriorityFeePerGas: "0x1",
+                    },
+                  },
+                },
+              }),
+            ERRORS.GENERAL.INVALID_CONFIG
+          );
+        });
+
+        it("Should fail with a non-positive faucet interval", function () {
+          for (const interval of [0, -60, NaN]) {
+            expectHardhatError(
+              () =>
+                validateConfig({
+                  networks: {
+                    [HARDHAT_NETWORK_NAME]: {
+                      faucetRateLimit: { maxAmount: "1000", interval },
+                    },
+                  },
+                }),
+              ERRORS.GENERAL.INVALID_CONFIG
+            );
+          }
+
+          validateConfig({
+            networks: {
+              [HARDHAT_NETWORK_NAME]: {
+                faucetRateLimit: { maxAmount: "1000", interval: 60 },
+              },
+            },
+          });
         });
 
         describe("HardhatNetworkHDAccounstConfig", function () {
@@ -1596,6 +1786,17 @@ describe("Config validation", function () {
             forking: {
               url: "asd",
               blockNumber: 123,
+              prefetch: [
+                {
+                  address: "0x0d2026b3EE6eC71FC6746ADb6311F6d3Ba1C000B",
+                  storageRanges: [{ from: 0, to: 10 }],
+                },
+              ],
+            },
+            gasPriceOracle: {
+              blocks: 20,
+              percentile: 60,
+              fallbackMaxPriorityFeePerGas: "1000000000",
             },
           },
           localhost: {
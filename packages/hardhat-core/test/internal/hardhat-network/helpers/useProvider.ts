@@ -7,9 +7,11 @@ import {
   MempoolOrder,
 } from "../../../../src/internal/hardhat-network/provider/node-types";
 import { createHardhatNetworkProvider } from "../../../../src/internal/hardhat-network/provider/provider";
+import { FaucetRateLimit } from "../../../../src/internal/hardhat-network/provider/utils/faucetRateLimiter";
 import {
   EIP1193Provider,
   EthereumProvider,
+  HardhatNetworkHDAccountsConfig,
   HardhatNetworkMempoolConfig,
   HardhatNetworkMiningConfig,
 } from "../../../../src/types";
@@ -56,6 +58,9 @@ export interface UseProviderOptions {
   coinbase?: string;
   chains?: HardhatNetworkChainsConfig;
   forkBlockNumber?: number;
+  throwOnCallFailures?: boolean;
+  hdAccounts?: HardhatNetworkHDAccountsConfig;
+  faucetRateLimit?: FaucetRateLimit;
 }
 
 export function useProvider({
@@ -74,6 +79,9 @@ export function useProvider({
   mempool = DEFAULT_MEMPOOL_CONFIG,
   coinbase,
   chains = defaultHardhatNetworkParams.chains,
+  throwOnCallFailures = true,
+  hdAccounts,
+  faucetRateLimit,
 }: UseProviderOptions = {}) {
   beforeEach("Initialize provider", async function () {
     this.logger = new FakeModulesLogger();
@@ -90,7 +98,7 @@ export function useProvider({
             : Number(initialBaseFeePerGas),
         minGasPrice: 0n,
         throwOnTransactionFailures: true,
-        throwOnCallFailures: true,
+        throwOnCallFailures,
         automine: mining.auto,
         intervalMining: mining.interval,
         mempoolOrder: mempool.order as MempoolOrder,
@@ -101,6 +109,8 @@ export function useProvider({
         coinbase,
         allowBlocksWithSameTimestamp,
         enableTransientStorage: false,
+        hdAccounts,
+        faucetRateLimit,
       },
       {
         enabled: loggerEnabled,
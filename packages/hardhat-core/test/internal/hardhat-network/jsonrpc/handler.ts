@@ -0,0 +1,206 @@
+import { assert } from "chai";
+import debug from "debug";
+import { EventEmitter } from "events";
+
+import { InvalidArgumentsError } from "../../../../src/internal/core/providers/errors";
+import {
+  assertServerRequestOptions,
+  CORRELATION_ID_HEADER,
+  getCorrelationId,
+  JsonRpcHandler,
+} from "../../../../src/internal/hardhat-network/jsonrpc/handler";
+
+describe("getCorrelationId", function () {
+  const request = {
+    jsonrpc: "2.0",
+    id: 1,
+    method: "eth_blockNumber",
+    params: [],
+  };
+
+  it("returns undefined if the request has no correlation id", function () {
+    assert.isUndefined(getCorrelationId(request));
+  });
+
+  it("uses the correlation id header", function () {
+    assert.equal(getCorrelationId(request, "test-1"), "test-1");
+    assert.equal(getCorrelationId(request, ["test-1", "test-2"]), "test-1");
+  });
+
+  it("prefers the correlationId field of the request", function () {
+    assert.equal(
+      getCorrelationId({ ...request, correlationId: "test-2" } as any, "test-1"),
+      "test-2"
+    );
+  });
+});
+
+describe("assertServerRequestOptions", function () {
+  const TX_HASH =
+    "0x09cfae3d6a1a2e3d4d58e3c3bba9e55aedd3a16df2b82f27ef04e4cd8a3a0f1a";
+  const BLOCK_HASH =
+    "0x3c3f2b4ec0a3d5a6e6b9d26d5e1a04a5b5a8f5f3c2ad2c0a2f6b5e3f0a1b2c3d";
+
+  it("accepts traces that aren't exported", function () {
+    assert.doesNotThrow(() =>
+      assertServerRequestOptions({
+        jsonrpc: "2.0",
+        id: 1,
+        method: "debug_traceTransaction",
+        params: [TX_HASH, { disableMemory: true }],
+      })
+    );
+  });
+
+  it("rejects the exportToFile option", function () {
+    assert.throws(
+      () =>
+        assertServerRequestOptions({
+          jsonrpc: "2.0",
+          id: 1,
+          method: "debug_traceTransaction",
+          params: [TX_HASH, { exportToFile: true }],
+        }),
+      InvalidArgumentsError,
+      "exportToFile"
+    );
+  });
+
+  it("rejects the exportToFile option of debug_traceBlockByNumber", function () {
+    assert.throws(
+      () =>
+        assertServerRequestOptions({
+          jsonrpc: "2.0",
+          id: 1,
+          method: "debug_traceBlockByNumber",
+          params: ["latest", { exportToFile: true }],
+        }),
+      InvalidArgumentsError,
+      "exportToFile"
+    );
+  });
+
+  it("rejects the exportToFile option of debug_traceBlockByHash", function () {
+    assert.throws(
+      () =>
+        assertServerRequestOptions({
+          jsonrpc: "2.0",
+          id: 1,
+          method: "debug_traceBlockByHash",
+          params: [BLOCK_HASH, { exportToFile: true }],
+        }),
+      InvalidArgumentsError,
+      "exportToFile"
+    );
+  });
+});
+
+describe("JsonRpcHandler", function () {
+  describe("handleWs", function () {
+    const LOG_NAMESPACE = "hardhat:core:hardhat-network:jsonrpc";
+
+    let logs: string[];
+    let previousNamespaces: string;
+    let previousLog: typeof debug.log;
+
+    beforeEach(function () {
+      logs = [];
+
+      previousNamespaces = debug.disable();
+      debug.enable(LOG_NAMESPACE);
+
+      previousLog = debug.log;
+      debug.log = (...args: any[]) => {
+        logs.push(args.join(" "));
+      };
+    });
+
+    afterEach(function () {
+      debug.log = previousLog;
+      debug.disable();
+      debug.enable(previousNamespaces);
+    });
+
+    it("uses the correlation id header of the connection", async function () {
+      const provider = Object.assign(new EventEmitter(), {
+        request: async () => "0x1",
+      });
+      const handler = new JsonRpcHandler(provider);
+
+      const ws = new EventEmitter() as any;
+      const response = new Promise<string>((resolve) => {
+        ws.send = resolve;
+      });
+
+      await handler.handleWs(ws, {
+        headers: { [CORRELATION_ID_HEADER]: "test-1" },
+      } as any);
+
+      ws.emit(
+        "message",
+        JSON.stringify({
+          jsonrpc: "2.0",
+          id: 1,
+          method: "eth_blockNumber",
+          params: [],
+        })
+      );
+
+      assert.deepEqual(JSON.parse(await response), {
+        jsonrpc: "2.0",
+        id: 1,
+        result: "0x1",
+      });
+      assert.isTrue(
+        logs.some((line) => line.includes("[test-1] Handling eth_blockNumber"))
+      );
+    });
+
+    it("prints the correlation ids if it has a printLine function", async function () {
+      const provider = Object.assign(new EventEmitter(), {
+        request: async () => "0x1",
+      });
+      const printedLines: string[] = [];
+      const handler = new JsonRpcHandler(provider, undefined, (line) =>
+        printedLines.push(line)
+      );
+
+      const ws = new EventEmitter() as any;
+      const responses: string[] = [];
+      let resolveResponses: () => void;
+      const allResponses = new Promise<void>((resolve) => {
+        resolveResponses = resolve;
+      });
+      ws.send = (response: string) => {
+        responses.push(response);
+        if (responses.length === 2) {
+          resolveResponses();
+        }
+      };
+
+      await handler.handleWs(ws);
+
+      for (const [id, correlationId] of [
+        [1, "test-1"],
+        [2, undefined],
+      ]) {
+        ws.emit(
+          "message",
+          JSON.stringify({
+            jsonrpc: "2.0",
+            id,
+            method: "eth_blockNumber",
+            params: [],
+            correlationId,
+          })
+        );
+      }
+
+      await allResponses;
+
+      assert.deepEqual(printedLines, [
+        "Request eth_blockNumber with correlation id test-1",
+      ]);
+    });
+  });
+});
@@ -0,0 +1,91 @@
+import { assert } from "chai";
+
+import { MethodNotFoundError } from "../../../../src/internal/core/providers/errors";
+import { MethodFilter } from "../../../../src/internal/hardhat-network/jsonrpc/method-filter";
+
+describe("MethodFilter", function () {
+  it("accepts every method by default", function () {
+    const filter = new MethodFilter();
+
+    assert.isTrue(filter.isAllowed("eth_sendTransaction"));
+    assert.isTrue(filter.isAllowed("hardhat_setBalance"));
+  });
+
+  it("only accepts methods that expose private keys if allowed by name", function () {
+    assert.isFalse(new MethodFilter().isAllowed("hardhat_deriveAccount"));
+    assert.isFalse(
+      new MethodFilter({ allowedMethods: ["hardhat_*"] }).isAllowed(
+        "hardhat_deriveAccount"
+      )
+    );
+
+    assert.isTrue(
+      new MethodFilter({
+        allowedMethods: ["eth_*", "hardhat_deriveAccount"],
+      }).isAllowed("hardhat_deriveAccount")
+    );
+    assert.isFalse(
+      new MethodFilter({
+        readOnly: true,
+        allowedMethods: ["hardhat_deriveAccount"],
+      }).isAllowed("hardhat_deriveAccount")
+    );
+  });
+
+  it("rejects disabled methods and namespaces", function () {
+    const filter = new MethodFilter({
+      disabledMethods: ["hardhat_*", "evm_*", "debug_traceCall"],
+    });
+
+    assert.isFalse(filter.isAllowed("hardhat_setBalance"));
+    assert.isFalse(filter.isAllowed("evm_mine"));
+    assert.isFalse(filter.isAllowed("debug_traceCall"));
+    assert.isTrue(filter.isAllowed("debug_traceTransaction"));
+    assert.isTrue(filter.isAllowed("eth_sendTransaction"));
+  });
+
+  it("only accepts the allowed methods if they are set", function () {
+    const filter = new MethodFilter({
+      allowedMethods: ["eth_*", "net_version"],
+      disabledMethods: ["eth_sendTransaction"],
+    });
+
+    assert.isTrue(filter.isAllowed("eth_call"));
+    assert.isTrue(filter.isAllowed("net_version"));
+    assert.isFalse(filter.isAllowed("net_listening"));
+    assert.isFalse(filter.isAllowed("evm_mine"));
+    assert.isFalse(filter.isAllowed("eth_sendTransaction"));
+  });
+
+  it("applies the read-only restrictions on top of the lists", function () {
+    const filter = new MethodFilter({
+      readOnly: true,
+      disabledMethods: ["debug_*"],
+    });
+
+    assert.isTrue(filter.isAllowed("eth_getBalance"));
+    assert.isFalse(filter.isAllowed("eth_sendTransaction"));
+    assert.isFalse(filter.isAllowed("debug_traceTransaction"));
+  });
+
+  it("only accepts the faucet in read-only filters that allow it", function () {
+    assert.isFalse(
+      new MethodFilter({ readOnly: true }).isAllowed("hardhat_requestFunds")
+    );
+
+    const filter = new MethodFilter({ readOnly: true, allowFaucet: true });
+    assert.isTrue(filter.isAllowed("hardhat_requestFunds"));
+    assert.isFalse(filter.isAllowed("hardhat_setBalance"));
+  });
+
+  it("throws a MethodNotFoundError for rejected methods", function () {
+    const filter = new MethodFilter({ disabledMethods: ["evm_*"] });
+
+    assert.throws(
+      () => filter.assertAllowed("evm_mine"),
+      MethodNotFoundError,
+      "Method evm_mine is disabled in this server"
+    );
+    assert.doesNotThrow(() => filter.assertAllowed("eth_call"));
+  });
+});
@@ -0,0 +1,37 @@
+import { assert } from "chai";
+
+import { isReadOnlyMethod } from "../../../../src/internal/hardhat-network/jsonrpc/read-only-methods";
+
+describe("isReadOnlyMethod", function () {
+  it("accepts methods that only read the chain", function () {
+    for (const method of [
+      "eth_getBalance",
+      "eth_getLogs",
+      "eth_call",
+      "eth_subscribe",
+      "debug_traceTransaction",
+      "hardhat_metadata",
+      "net_version",
+    ]) {
+      assert.isTrue(isReadOnlyMethod(method), method);
+    }
+  });
+
+  it("rejects methods that modify the chain, sign or expose keys", function () {
+    for (const method of [
+      "eth_sendTransaction",
+      "eth_sendRawTransaction",
+      "eth_sign",
+      "eth_signTypedData_v4",
+      "personal_sign",
+      "evm_mine",
+      "evm_revert",
+      "hardhat_setBalance",
+      "hardhat_requestFunds",
+      "hardhat_reset",
+      "hardhat_deriveAccount",
+    ]) {
+      assert.isFalse(isReadOnlyMethod(method), method);
+    }
+  });
+});
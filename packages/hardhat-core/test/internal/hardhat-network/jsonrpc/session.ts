@@ -0,0 +1,100 @@
+import { assert } from "chai";
+
+import { InvalidArgumentsError } from "../../../../src/internal/core/providers/errors";
+import { ConnectionSession } from "../../../../src/internal/hardhat-network/jsonrpc/session";
+
+const SENDER = "0xf39fd6e51aad88f6f4ce6ab8827279cfffb92266";
+const OTHER_SENDER = "0x70997970c51812dc3a010c7d01b50e0d17dc79c8";
+
+function request(method: string, params: any[]) {
+  return { jsonrpc: "2.0", id: 1, method, params };
+}
+
+describe("ConnectionSession", function () {
+  let session: ConnectionSession;
+
+  beforeEach(function () {
+    session = new ConnectionSession();
+  });
+
+  it("doesn't modify requests when no defaults were set", function () {
+    const req = request("eth_call", [{ to: SENDER }]);
+
+    assert.deepEqual(session.applyDefaults(req), req);
+  });
+
+  it("sets the default sender when it's missing", function () {
+    session.setDefaults([{ from: SENDER }]);
+
+    const req = session.applyDefaults(
+      request("eth_sendTransaction", [{ to: OTHER_SENDER }])
+    );
+
+    assert.deepEqual(req.params, [{ to: OTHER_SENDER, from: SENDER }]);
+  });
+
+  it("doesn't override an explicit sender", function () {
+    session.setDefaults([{ from: SENDER }]);
+
+    const req = session.applyDefaults(
+      request("eth_sendTransaction", [{ from: OTHER_SENDER }])
+    );
+
+    assert.deepEqual(req.params, [{ from: OTHER_SENDER }]);
+  });
+
+  it("appends the default block tag when it's missing", function () {
+    session.setDefaults([{ blockTag: "0x10" }]);
+
+    assert.deepEqual(
+      session.applyDefaults(request("eth_getBalance", [SENDER])).params,
+      [SENDER, "0x10"]
+    );
+    assert.deepEqual(
+      session.applyDefaults(request("eth_getStorageAt", [SENDER, "0x0"]))
+        .params,
+      [SENDER, "0x0", "0x10"]
+    );
+    assert.deepEqual(
+      session.applyDefaults(request("eth_getBalance", [SENDER, "latest"]))
+        .params,
+      [SENDER, "latest"]
+    );
+  });
+
+  it("tags the transactions that don't have a tag", function () {
+    session.setDefaults([{ tag: { developer: "alice" } }]);
+
+    assert.deepEqual(
+      session.applyDefaults(request("eth_sendTransaction", [{ to: SENDER }]))
+        .params,
+      [{ to: SENDER, tag: { developer: "alice" } }]
+    );
+    assert.deepEqual(
+      session.applyDefaults(
+        request("eth_sendTransaction", [{ to: SENDER, tag: "explicit" }])
+      ).params,
+      [{ to: SENDER, tag: "explicit" }]
+    );
+    assert.deepEqual(
+      session.applyDefaults(request("eth_call", [{ to: SENDER }])).params,
+      [{ to: SENDER }]
+    );
+  });
+
+  it("clears the defaults when they are set again", function () {
+    session.setDefaults([{ from: SENDER, blockTag: "pending", tag: "a" }]);
+    session.setDefaults([{}]);
+
+    const req = request("eth_call", [{ to: SENDER }]);
+
+    assert.deepEqual(session.applyDefaults(req), req);
+  });
+
+  it("rejects invalid defaults", function () {
+    assert.throws(
+      () => session.setDefaults([{ from: "0x1234" }]),
+      InvalidArgumentsError
+    );
+  });
+});
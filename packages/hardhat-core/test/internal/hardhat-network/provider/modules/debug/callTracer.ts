@@ -0,0 +1,54 @@
+import { assert } from "chai";
+
+import { numberToRpcQuantity } from "../../../../../../src/internal/core/jsonrpc/types/base-types";
+import {
+  DEFAULT_ACCOUNTS_ADDRESSES,
+  PROVIDERS,
+} from "../../../helpers/providers";
+
+describe("debug_trace* with the callTracer", function () {
+  PROVIDERS.forEach(({ name, useProvider }) => {
+    describe(`${name} provider`, function () {
+      useProvider();
+
+      it("returns the transaction as the top-level frame", async function () {
+        const txHash = await this.provider.send("eth_sendTransaction", [
+          {
+            from: DEFAULT_ACCOUNTS_ADDRESSES[1],
+            to: DEFAULT_ACCOUNTS_ADDRESSES[2],
+            value: numberToRpcQuantity(1),
+            gas: numberToRpcQuantity(21_000),
+          },
+        ]);
+
+        const frame = await this.provider.send("debug_traceTransaction", [
+          txHash,
+          { tracer: "callTracer" },
+        ]);
+
+        assert.deepEqual(frame, {
+          type: "CALL",
+          from: DEFAULT_ACCOUNTS_ADDRESSES[1],
+          to: DEFAULT_ACCOUNTS_ADDRESSES[2],
+          value: numberToRpcQuantity(1),
+          gas: numberToRpcQuantity(21_000),
+          gasUsed: numberToRpcQuantity(21_000),
+          input: "0x",
+        });
+      });
+
+      it("uses the address of the deployed contract for creations", async function () {
+        // PUSH1 0x00 PUSH1 0x00 RETURN, which deploys an empty contract
+        const frame = await this.provider.send("debug_traceCall", [
+          { from: DEFAULT_ACCOUNTS_ADDRESSES[1], data: "0x60006000f3" },
+          "latest",
+          { tracer: "callTracer" },
+        ]);
+
+        assert.equal(frame.type, "CREATE");
+        assert.isString(frame.to);
+        assert.isUndefined(frame.error);
+      });
+    });
+  });
+});
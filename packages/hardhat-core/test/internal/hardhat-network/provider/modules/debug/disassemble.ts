@@ -0,0 +1,103 @@
+import { assert } from "chai";
+
+import { numberToRpcQuantity } from "../../../../../../src/internal/core/jsonrpc/types/base-types";
+import { HARDHAT_NETWORK_SUPPORTED_HARDFORKS } from "../../../../../../src/internal/constants";
+import {
+  disassemble,
+  OPCODE_HARDFORKS,
+} from "../../../../../../src/internal/hardhat-network/provider/utils/disassemble";
+import { PROVIDERS } from "../../../helpers/providers";
+import { retrieveCommon } from "../../../helpers/retrieveCommon";
+import { deployContract } from "../../../helpers/transactions";
+
+describe("debug_disassemble", function () {
+  PROVIDERS.forEach(({ name, useProvider }) => {
+    describe(`${name} provider`, function () {
+      useProvider();
+
+      it("disassembles the given code", async function () {
+        // 20 bytes of code, which would be an address in the old format
+        const code = `0x${"5f".repeat(19)}00`;
+
+        const { instructions } = await this.provider.send(
+          "debug_disassemble",
+          [{ code }]
+        );
+
+        assert.lengthOf(instructions, 20);
+        assert.deepEqual(instructions[0], { pc: 0, opcode: "PUSH0" });
+        assert.deepEqual(instructions[19], { pc: 19, opcode: "STOP" });
+      });
+
+      it("disassembles the code of an account", async function () {
+        // Deploys the code 0x5f00 (PUSH0 STOP)
+        const address = await deployContract(
+          this.provider,
+          "0x615f006000526002601ef3"
+        );
+
+        const { instructions } = await this.provider.send(
+          "debug_disassemble",
+          [{ address }, "latest"]
+        );
+
+        assert.deepEqual(instructions, [
+          { pc: 0, opcode: "PUSH0" },
+          { pc: 1, opcode: "STOP" },
+        ]);
+      });
+
+      it("rejects a bare hex string", async function () {
+        await assert.isRejected(
+          this.provider.send("debug_disassemble", ["0x5f00"])
+        );
+      });
+    });
+  });
+});
+
+describe("debug_disassemble opcode hardforks", function () {
+  const CALL_GAS = 1_000_000;
+  const CONTRACT_ADDRESS = "0x0000000000000000000000000000000000001234";
+
+  // Checks the opcodes that the disassembler recognizes in each hardfork
+  // against the EVM of the node. An opcode that isn't active halts the
+  // execution, which consumes all the gas of the call. Each opcode is run
+  // with enough zeros in the stack for all of its inputs, so that it doesn't
+  // halt if it's active.
+  for (const hardfork of HARDHAT_NETWORK_SUPPORTED_HARDFORKS) {
+    describe(hardfork, function () {
+      PROVIDERS[0].useProvider({ hardfork });
+
+      it("recognizes the opcodes that the node runs", async function () {
+        const common = await retrieveCommon(this.hardhatNetworkProvider);
+
+        for (const opcode of Object.keys(OPCODE_HARDFORKS).map(Number)) {
+          // PUSH1 0x00 (7 times) <opcode> STOP
+          const code = Buffer.concat([
+            Buffer.from("6000".repeat(7), "hex"),
+            Buffer.from([opcode, 0x00]),
+          ]);
+
+          await this.provider.send("hardhat_setCode", [
+            CONTRACT_ADDRESS,
+            `0x${code.toString("hex")}`,
+          ]);
+
+          const trace = await this.provider.send("debug_traceCall", [
+            { to: CONTRACT_ADDRESS, gas: numberToRpcQuantity(CALL_GAS) },
+            "latest",
+          ]);
+
+          const instruction = disassemble(code, common).instructions[7];
+
+          assert.equal(
+            !instruction.opcode.startsWith("UNRECOGNIZED_"),
+            trace.gas < CALL_GAS,
+            `Opcode 0x${opcode.toString(16)} (${instruction.opcode})`
+          );
+        }
+      });
+    });
+  }
+});
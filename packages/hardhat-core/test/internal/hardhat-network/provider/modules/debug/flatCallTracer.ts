@@ -0,0 +1,58 @@
+import { assert } from "chai";
+
+import { numberToRpcQuantity } from "../../../../../../src/internal/core/jsonrpc/types/base-types";
+import {
+  DEFAULT_ACCOUNTS_ADDRESSES,
+  PROVIDERS,
+} from "../../../helpers/providers";
+
+describe("debug_trace* with the flatCallTracer", function () {
+  PROVIDERS.forEach(({ name, useProvider }) => {
+    describe(`${name} provider`, function () {
+      useProvider();
+
+      it("returns the flat traces of a transaction with its position", async function () {
+        const txHash = await this.provider.send("eth_sendTransaction", [
+          {
+            from: DEFAULT_ACCOUNTS_ADDRESSES[1],
+            to: DEFAULT_ACCOUNTS_ADDRESSES[2],
+            value: numberToRpcQuantity(1),
+            gas: numberToRpcQuantity(21_000),
+          },
+        ]);
+
+        const { blockHash, blockNumber } = await this.provider.send(
+          "eth_getTransactionByHash",
+          [txHash]
+        );
+
+        const traces = await this.provider.send("debug_traceTransaction", [
+          txHash,
+          { tracer: "flatCallTracer" },
+        ]);
+
+        assert.lengthOf(traces, 1);
+        assert.equal(traces[0].type, "call");
+        assert.deepEqual(traces[0].traceAddress, []);
+        assert.equal(traces[0].blockHash, blockHash);
+        assert.equal(traces[0].blockNumber, Number(blockNumber));
+        assert.equal(traces[0].transactionHash, txHash);
+        assert.equal(traces[0].transactionPosition, 0);
+      });
+
+      it("leaves out the position of calls", async function () {
+        const [trace] = await this.provider.send("debug_traceCall", [
+          {
+            from: DEFAULT_ACCOUNTS_ADDRESSES[1],
+            to: DEFAULT_ACCOUNTS_ADDRESSES[2],
+          },
+          "latest",
+          { tracer: "flatCallTracer" },
+        ]);
+
+        assert.equal(trace.action.callType, "call");
+        assert.notProperty(trace, "transactionHash");
+      });
+    });
+  });
+});
@@ -0,0 +1,58 @@
+import { assert } from "chai";
+
+import { numberToRpcQuantity } from "../../../../../../src/internal/core/jsonrpc/types/base-types";
+import {
+  DEFAULT_ACCOUNTS_ADDRESSES,
+  PROVIDERS,
+} from "../../../helpers/providers";
+
+describe("debug_trace* with the gasProfileTracer", function () {
+  PROVIDERS.forEach(({ name, useProvider }) => {
+    describe(`${name} provider`, function () {
+      useProvider();
+
+      it("returns the gas used by the transaction", async function () {
+        const txHash = await this.provider.send("eth_sendTransaction", [
+          {
+            from: DEFAULT_ACCOUNTS_ADDRESSES[1],
+            to: DEFAULT_ACCOUNTS_ADDRESSES[2],
+            gas: numberToRpcQuantity(21_000),
+          },
+        ]);
+
+        const profile = await this.provider.send("debug_traceTransaction", [
+          txHash,
+          { tracer: "gasProfileTracer" },
+        ]);
+
+        assert.deepEqual(profile, {
+          type: "CALL",
+          address: DEFAULT_ACCOUNTS_ADDRESSES[2],
+          gasUsed: numberToRpcQuantity(21_000),
+          selfGasUsed: numberToRpcQuantity(21_000),
+        });
+      });
+
+      it("attributes the gas of calls to their contracts", async function () {
+        // PUSH1 0 (x4) PUSH20 <callee> GAS STATICCALL STOP
+        const callee = DEFAULT_ACCOUNTS_ADDRESSES[2].slice(2);
+        const profile = await this.provider.send("debug_traceCall", [
+          {
+            from: DEFAULT_ACCOUNTS_ADDRESSES[1],
+            data: `0x600060006000600073${callee}5afa00`,
+          },
+          "latest",
+          { tracer: "gasProfileTracer" },
+        ]);
+
+        assert.equal(profile.type, "CREATE");
+        assert.lengthOf(profile.calls, 1);
+        assert.equal(profile.calls[0].type, "STATICCALL");
+        assert.equal(
+          profile.calls[0].address,
+          DEFAULT_ACCOUNTS_ADDRESSES[2].toLowerCase()
+        );
+      });
+    });
+  });
+});
@@ -0,0 +1,108 @@
+import { assert } from "chai";
+import { RLP } from "@nomicfoundation/ethereumjs-rlp";
+import { bytesToHex, hexToBytes } from "@nomicfoundation/ethereumjs-util";
+
+import { numberToRpcQuantity } from "../../../../../../src/internal/core/jsonrpc/types/base-types";
+import { keccak256 } from "../../../../../../src/internal/util/keccak";
+import { assertInvalidArgumentsError } from "../../../helpers/assertions";
+import {
+  DEFAULT_ACCOUNTS_ADDRESSES,
+  PROVIDERS,
+} from "../../../helpers/providers";
+
+describe("debug_getRawBlock", function () {
+  PROVIDERS.forEach(({ name, useProvider }) => {
+    describe(`${name} provider`, function () {
+      useProvider();
+
+      let txHashes: string[];
+      let block: { hash: string; number: string };
+
+      beforeEach(async function () {
+        await this.provider.send("evm_setAutomine", [false]);
+
+        txHashes = [
+          await this.provider.send("eth_sendTransaction", [
+            {
+              from: DEFAULT_ACCOUNTS_ADDRESSES[1],
+              to: DEFAULT_ACCOUNTS_ADDRESSES[2],
+              gas: numberToRpcQuantity(21_000),
+              gasPrice: numberToRpcQuantity(10n ** 10n),
+            },
+          ]),
+          await this.provider.send("eth_sendTransaction", [
+            {
+              from: DEFAULT_ACCOUNTS_ADDRESSES[1],
+              to: DEFAULT_ACCOUNTS_ADDRESSES[2],
+              nonce: numberToRpcQuantity(1),
+              gas: numberToRpcQuantity(21_000),
+              value: numberToRpcQuantity(1),
+            },
+          ]),
+        ];
+
+        await this.provider.send("evm_mine");
+        block = await this.provider.send("eth_getBlockByNumber", [
+          "latest",
+          false,
+        ]);
+      });
+
+      it("returns the block encoded with its header and transactions", async function () {
+        const rawBlock = await this.provider.send("debug_getRawBlock", [
+          block.hash,
+        ]);
+
+        const [header, transactions, uncles] = RLP.decode(
+          hexToBytes(rawBlock)
+        ) as any[];
+
+        assert.equal(bytesToHex(keccak256(RLP.encode(header))), block.hash);
+        assert.lengthOf(uncles, 0);
+        assert.lengthOf(transactions, 2);
+
+        // The legacy transaction is embedded as a list, and the EIP-1559
+        // one as its serialized form
+        assert.equal(
+          bytesToHex(RLP.encode(transactions[0])),
+          await this.provider.send("debug_getRawTransaction", [txHashes[0]])
+        );
+        assert.equal(
+          bytesToHex(transactions[1]),
+          await this.provider.send("debug_getRawTransaction", [txHashes[1]])
+        );
+      });
+
+      it("accepts block numbers and tags", async function () {
+        const rawBlock = await this.provider.send("debug_getRawBlock", [
+          block.hash,
+        ]);
+
+        for (const blockTag of [
+          "latest",
+          block.number,
+          { blockNumber: block.number },
+          { blockHash: block.hash },
+        ]) {
+          assert.equal(
+            await this.provider.send("debug_getRawBlock", [blockTag]),
+            rawBlock
+          );
+        }
+      });
+
+      it("returns null if the block doesn't exist", async function () {
+        assert.isNull(await this.provider.send("debug_getRawBlock", ["0x100"]));
+      });
+
+      it("rejects the pending block", async function () {
+        await assertInvalidArgumentsError(
+          this.provider,
+          "debug_getRawBlock",
+          ["pending"],
+          "doesn't support the pending block"
+        );
+      });
+    });
+  });
+});
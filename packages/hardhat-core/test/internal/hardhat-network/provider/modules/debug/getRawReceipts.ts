@@ -0,0 +1,120 @@
+import { assert } from "chai";
+import { RLP } from "@nomicfoundation/ethereumjs-rlp";
+import { bytesToHex, hexToBytes } from "@nomicfoundation/ethereumjs-util";
+
+import { numberToRpcQuantity } from "../../../../../../src/internal/core/jsonrpc/types/base-types";
+import { assertInvalidArgumentsError } from "../../../helpers/assertions";
+import {
+  DEFAULT_ACCOUNTS_ADDRESSES,
+  PROVIDERS,
+} from "../../../helpers/providers";
+
+// Init code that logs 32 bytes with LOG1 and deploys an empty contract
+const LOGGING_INIT_CODE = "0x60aa600052600160206000a100";
+
+describe("debug_getRawReceipts", function () {
+  PROVIDERS.forEach(({ name, useProvider }) => {
+    describe(`${name} provider`, function () {
+      useProvider();
+
+      let txHashes: string[];
+      let block: { hash: string; number: string };
+
+      beforeEach(async function () {
+        await this.provider.send("evm_setAutomine", [false]);
+
+        txHashes = [
+          await this.provider.send("eth_sendTransaction", [
+            {
+              from: DEFAULT_ACCOUNTS_ADDRESSES[1],
+              to: DEFAULT_ACCOUNTS_ADDRESSES[2],
+              gas: numberToRpcQuantity(21_000),
+              gasPrice: numberToRpcQuantity(10n ** 10n),
+            },
+          ]),
+          await this.provider.send("eth_sendTransaction", [
+            {
+              from: DEFAULT_ACCOUNTS_ADDRESSES[1],
+              nonce: numberToRpcQuantity(1),
+              data: LOGGING_INIT_CODE,
+            },
+          ]),
+        ];
+
+        await this.provider.send("evm_mine");
+        block = await this.provider.send("eth_getBlockByNumber", [
+          "latest",
+          false,
+        ]);
+      });
+
+      it("returns the encoded receipts of a block in order", async function () {
+        const rawReceipts: string[] = await this.provider.send(
+          "debug_getRawReceipts",
+          [block.hash]
+        );
+
+        assert.lengthOf(rawReceipts, 2);
+
+        const legacyReceipt = await this.provider.send(
+          "eth_getTransactionReceipt",
+          [txHashes[0]]
+        );
+        const [status, cumulativeGasUsed, logsBloom, logs] = RLP.decode(
+          hexToBytes(rawReceipts[0])
+        ) as any[];
+
+        assert.equal(bytesToHex(status), "0x01");
+        assert.equal(
+          bytesToHex(cumulativeGasUsed),
+          legacyReceipt.cumulativeGasUsed
+        );
+        assert.equal(bytesToHex(logsBloom), legacyReceipt.logsBloom);
+        assert.lengthOf(logs, 0);
+
+        // Typed receipts are prefixed with the type of their transaction
+        const receipt = await this.provider.send("eth_getTransactionReceipt", [
+          txHashes[1],
+        ]);
+        const rawReceipt = hexToBytes(rawReceipts[1]);
+        assert.equal(rawReceipt[0], 2);
+
+        const [, , , [[address, topics, data]]] = RLP.decode(
+          rawReceipt.slice(1)
+        ) as any[];
+
+        assert.equal(bytesToHex(address), receipt.logs[0].address);
+        assert.deepEqual(topics.map(bytesToHex), receipt.logs[0].topics);
+        assert.equal(bytesToHex(data), receipt.logs[0].data);
+      });
+
+      it("accepts block numbers and tags", async function () {
+        const rawReceipts = await this.provider.send("debug_getRawReceipts", [
+          block.hash,
+        ]);
+
+        for (const blockTag of ["latest", block.number]) {
+          assert.deepEqual(
+            await this.provider.send("debug_getRawReceipts", [blockTag]),
+            rawReceipts
+          );
+        }
+      });
+
+      it("returns null if the block doesn't exist", async function () {
+        assert.isNull(
+          await this.provider.send("debug_getRawReceipts", ["0x100"])
+        );
+      });
+
+      it("rejects the pending block", async function () {
+        await assertInvalidArgumentsError(
+          this.provider,
+          "debug_getRawReceipts",
+          ["pending"],
+          "doesn't support the pending block"
+        );
+      });
+    });
+  });
+});
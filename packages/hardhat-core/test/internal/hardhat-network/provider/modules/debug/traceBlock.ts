@@ -0,0 +1,83 @@
+import { assert } from "chai";
+
+import { numberToRpcQuantity } from "../../../../../../src/internal/core/jsonrpc/types/base-types";
+import { assertInvalidArgumentsError } from "../../../helpers/assertions";
+import {
+  DEFAULT_ACCOUNTS_ADDRESSES,
+  PROVIDERS,
+} from "../../../helpers/providers";
+
+describe("debug_traceBlockByNumber and debug_traceBlockByHash", function () {
+  PROVIDERS.forEach(({ name, useProvider }) => {
+    describe(`${name} provider`, function () {
+      useProvider();
+
+      let txHashes: string[];
+
+      beforeEach(async function () {
+        await this.provider.send("evm_setAutomine", [false]);
+
+        txHashes = [];
+        for (const nonce of [0, 1]) {
+          txHashes.push(
+            await this.provider.send("eth_sendTransaction", [
+              {
+                from: DEFAULT_ACCOUNTS_ADDRESSES[1],
+                to: DEFAULT_ACCOUNTS_ADDRESSES[2],
+                nonce: numberToRpcQuantity(nonce),
+                gas: numberToRpcQuantity(21_000),
+              },
+            ])
+          );
+        }
+
+        await this.provider.send("evm_mine");
+      });
+
+      it("traces the transactions of a block by number in order", async function () {
+        const traces = await this.provider.send("debug_traceBlockByNumber", [
+          "latest",
+          { disableMemory: true },
+        ]);
+
+        assert.deepEqual(
+          traces.map(({ txHash }: { txHash: string }) => txHash),
+          txHashes
+        );
+        assert.isFalse(traces[0].result.failed);
+      });
+
+      it("traces the transactions of a block by hash", async function () {
+        const { hash } = await this.provider.send("eth_getBlockByNumber", [
+          "latest",
+          false,
+        ]);
+
+        const traces = await this.provider.send("debug_traceBlockByHash", [
+          hash,
+        ]);
+
+        assert.deepEqual(
+          traces.map(({ txHash }: { txHash: string }) => txHash),
+          txHashes
+        );
+      });
+
+      it("rejects the pending block", async function () {
+        await assertInvalidArgumentsError(
+          this.provider,
+          "debug_traceBlockByNumber",
+          ["pending"],
+          "doesn't support the pending block"
+        );
+      });
+
+      it("throws if the block doesn't exist", async function () {
+        await assert.isRejected(
+          this.provider.send("debug_traceBlockByNumber", ["0x100"]),
+          "doesn't exist"
+        );
+      });
+    });
+  });
+});
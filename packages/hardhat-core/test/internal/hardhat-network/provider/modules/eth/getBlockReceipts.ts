@@ -0,0 +1,89 @@
+import { assert } from "chai";
+
+import { numberToRpcQuantity } from "../../../../../../src/internal/core/jsonrpc/types/base-types";
+import { assertInvalidArgumentsError } from "../../../helpers/assertions";
+import {
+  DEFAULT_ACCOUNTS_ADDRESSES,
+  PROVIDERS,
+} from "../../../helpers/providers";
+
+describe("eth_getBlockReceipts", function () {
+  PROVIDERS.forEach(({ name, useProvider }) => {
+    describe(`${name} provider`, function () {
+      useProvider();
+
+      let txHashes: string[];
+      let block: { hash: string; number: string };
+
+      beforeEach(async function () {
+        await this.provider.send("evm_setAutomine", [false]);
+
+        txHashes = [];
+        for (const nonce of [0, 1]) {
+          txHashes.push(
+            await this.provider.send("eth_sendTransaction", [
+              {
+                from: DEFAULT_ACCOUNTS_ADDRESSES[1],
+                to: DEFAULT_ACCOUNTS_ADDRESSES[2],
+                nonce: numberToRpcQuantity(nonce),
+                gas: numberToRpcQuantity(21_000),
+              },
+            ])
+          );
+        }
+
+        await this.provider.send("evm_mine");
+        block = await this.provider.send("eth_getBlockByNumber", [
+          "latest",
+          false,
+        ]);
+      });
+
+      function getTxHashes(receipts: Array<{ transactionHash: string }>) {
+        return receipts.map(({ transactionHash }) => transactionHash);
+      }
+
+      it("returns the receipts of a block in order", async function () {
+        for (const blockTag of [
+          "latest",
+          block.number,
+          block.hash,
+          { blockNumber: block.number },
+          { blockHash: block.hash },
+        ]) {
+          const receipts = await this.provider.send("eth_getBlockReceipts", [
+            blockTag,
+          ]);
+
+          assert.deepEqual(getTxHashes(receipts), txHashes);
+        }
+      });
+
+      it("returns the same receipts as eth_getTransactionReceipt", async function () {
+        const receipts = await this.provider.send("eth_getBlockReceipts", [
+          block.hash,
+        ]);
+
+        assert.deepEqual(
+          receipts[1],
+          await this.provider.send("eth_getTransactionReceipt", [txHashes[1]])
+        );
+      });
+
+      it("returns null if the block doesn't exist", async function () {
+        assert.isNull(
+          await this.provider.send("eth_getBlockReceipts", ["0x100"])
+        );
+      });
+
+      it("rejects the pending block", async function () {
+        await assertInvalidArgumentsError(
+          this.provider,
+          "eth_getBlockReceipts",
+          ["pending"],
+          "doesn't support the pending block"
+        );
+      });
+    });
+  });
+});
@@ -0,0 +1,65 @@
+import { assert } from "chai";
+
+import {
+  assertInvalidArgumentsError,
+  assertInvalidInputError,
+} from "../../../helpers/assertions";
+import { PROVIDERS } from "../../../helpers/providers";
+
+const HD_ACCOUNTS = {
+  mnemonic: "test test test test test test test test test test test junk",
+  initialIndex: 0,
+  count: 20,
+  path: "m/44'/60'/0'/0",
+  accountsBalance: "10000000000000000000000",
+  passphrase: "",
+};
+
+describe("hardhat_deriveAccount", function () {
+  PROVIDERS.forEach(({ name, useProvider }) => {
+    describe(`${name} provider`, function () {
+      describe("with accounts derived from a mnemonic", function () {
+        useProvider({ hdAccounts: HD_ACCOUNTS });
+
+        it("derives the account of an index", async function () {
+          assert.deepEqual(
+            await this.provider.send("hardhat_deriveAccount", [1]),
+            {
+              address: "0x70997970c51812dc3a010c7d01b50e0d17dc79c8",
+              privateKey:
+                "0x59c6995e998f97a5a0044966f0945389dc9e86dae88c7a8412f4603b6b78690d",
+            }
+          );
+        });
+
+        it("rejects invalid indexes", async function () {
+          await assertInvalidArgumentsError(
+            this.provider,
+            "hardhat_deriveAccount",
+            [-1],
+            "must be an integer"
+          );
+          await assertInvalidArgumentsError(
+            this.provider,
+            "hardhat_deriveAccount",
+            [2 ** 31],
+            "must be an integer"
+          );
+        });
+      });
+
+      describe("with accounts given as private keys", function () {
+        useProvider();
+
+        it("throws", async function () {
+          await assertInvalidInputError(
+            this.provider,
+            "hardhat_deriveAccount",
+            [1],
+            "can only be used if the accounts are derived from a mnemonic"
+          );
+        });
+      });
+    });
+  });
+});
@@ -0,0 +1,63 @@
+import { assert } from "chai";
+
+import { assertInvalidArgumentsError } from "../../../helpers/assertions";
+import { EXAMPLE_REVERT_CONTRACT } from "../../../helpers/contracts";
+import { PROVIDERS } from "../../../helpers/providers";
+import { deployContract } from "../../../helpers/transactions";
+
+describe("hardhat_diffCalls", function () {
+  PROVIDERS.forEach(({ name, useProvider }) => {
+    describe(`${name} provider`, function () {
+      useProvider();
+
+      it("compares calls run against numbered blocks", async function () {
+        const contractAddress = await deployContract(
+          this.provider,
+          `0x${EXAMPLE_REVERT_CONTRACT.bytecode.object}`
+        );
+        const blockNumber = await this.provider.send("eth_blockNumber");
+
+        const diff = await this.provider.send("hardhat_diffCalls", [
+          {
+            call: {
+              to: contractAddress,
+              data: `${EXAMPLE_REVERT_CONTRACT.selectors.f}${"0".repeat(63)}1`,
+            },
+            blockTag: blockNumber,
+          },
+          {
+            call: {
+              to: contractAddress,
+              data: EXAMPLE_REVERT_CONTRACT.selectors.reverts,
+            },
+            blockTag: blockNumber,
+          },
+        ]);
+
+        assert.deepEqual(diff.changes.success, { first: true, second: false });
+      });
+
+      it("rejects calls with state overrides", async function () {
+        const call = {
+          to: "0x0000000000000000000000000000000000000001",
+          data: "0x",
+        };
+
+        await assertInvalidArgumentsError(
+          this.provider,
+          "hardhat_diffCalls",
+          [
+            { call },
+            {
+              call,
+              stateOverrides: {
+                [call.to]: { balance: "0x1" },
+              },
+            },
+          ],
+          "hardhat_diffCalls doesn't support state overrides"
+        );
+      });
+    });
+  });
+});
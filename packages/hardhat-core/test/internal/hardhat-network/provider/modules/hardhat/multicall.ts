@@ -0,0 +1,94 @@
+import { assert } from "chai";
+
+import { EXAMPLE_REVERT_CONTRACT } from "../../../helpers/contracts";
+import { PROVIDERS } from "../../../helpers/providers";
+import { deployContract } from "../../../helpers/transactions";
+
+describe("hardhat_multicall", function () {
+  PROVIDERS.forEach(({ name, useProvider }) => {
+    [true, false].forEach((throwOnCallFailures) => {
+      describe(`${name} provider with throwOnCallFailures ${throwOnCallFailures}`, function () {
+        useProvider({ throwOnCallFailures });
+
+        let contractAddress: string;
+        let calls: Array<{ to: string; data: string }>;
+
+        beforeEach(async function () {
+          contractAddress = await deployContract(
+            this.provider,
+            `0x${EXAMPLE_REVERT_CONTRACT.bytecode.object}`
+          );
+
+          calls = [
+            {
+              to: contractAddress,
+              data: `${EXAMPLE_REVERT_CONTRACT.selectors.f}${"0".repeat(63)}1`,
+            },
+            {
+              to: contractAddress,
+              data: EXAMPLE_REVERT_CONTRACT.selectors.revertsWithReasonString,
+            },
+          ];
+        });
+
+        it("reports whether each call succeeded when tracing them", async function () {
+          const [success, failure] = await this.provider.send(
+            "hardhat_multicall",
+            [calls, "latest", { trace: true }]
+          );
+
+          assert.deepEqual(success, { success: true, returnData: "0x" });
+
+          assert.isFalse(failure.success);
+          assert.isTrue(failure.returnData.startsWith("0x08c379a0"));
+          assert.include(failure.error, "'a reason'");
+        });
+
+        if (throwOnCallFailures) {
+          it("reports the failures of eth_call", async function () {
+            const [success, failure] = await this.provider.send(
+              "hardhat_multicall",
+              [calls]
+            );
+
+            assert.deepEqual(success, { success: true, returnData: "0x" });
+
+            assert.isFalse(failure.success);
+            assert.isTrue(failure.returnData.startsWith("0x08c379a0"));
+            assert.include(failure.error, "'a reason'");
+          });
+        } else {
+          it("can't detect failures without tracing", async function () {
+            const [, failure] = await this.provider.send("hardhat_multicall", [
+              calls,
+            ]);
+
+            assert.isTrue(failure.success);
+            assert.isTrue(failure.returnData.startsWith("0x08c379a0"));
+          });
+        }
+
+        it("accepts EIP-1898 block objects", async function () {
+          const blockNumber = await this.provider.send("eth_blockNumber");
+
+          const results = await this.provider.send("hardhat_multicall", [
+            calls,
+            { blockNumber },
+            { trace: true },
+          ]);
+
+          assert.deepEqual(
+            results.map(({ success }: { success: boolean }) => success),
+            [true, false]
+          );
+        });
+
+        it("fails the whole request if the block doesn't exist", async function () {
+          await assert.isRejected(
+            this.provider.send("hardhat_multicall", [calls, "0x100"])
+          );
+        });
+      });
+    });
+  });
+});
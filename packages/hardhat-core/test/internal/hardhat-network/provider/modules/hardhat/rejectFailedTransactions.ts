@@ -0,0 +1,87 @@
+import { assert } from "chai";
+
+import { EthereumProvider } from "../../../../../../src/types";
+import { EXAMPLE_REVERT_CONTRACT } from "../../../helpers/contracts";
+import {
+  DEFAULT_ACCOUNTS_ADDRESSES,
+  PROVIDERS,
+} from "../../../helpers/providers";
+import { deployContract } from "../../../helpers/transactions";
+
+const OPTED_IN_SENDER = DEFAULT_ACCOUNTS_ADDRESSES[1];
+const OTHER_SENDER = DEFAULT_ACCOUNTS_ADDRESSES[2];
+
+describe("hardhat_setRejectFailedTransactions", function () {
+  PROVIDERS.forEach(({ name, useProvider }) => {
+    describe(`${name} provider`, function () {
+      useProvider();
+
+      let contractAddress: string;
+
+      beforeEach(async function () {
+        contractAddress = await deployContract(
+          this.provider,
+          `0x${EXAMPLE_REVERT_CONTRACT.bytecode.object}`
+        );
+
+        await this.provider.send("hardhat_setRejectFailedTransactions", [
+          OPTED_IN_SENDER,
+          true,
+        ]);
+      });
+
+      async function sendRevertingTransaction(
+        provider: EthereumProvider,
+        from: string
+      ) {
+        return provider.send("eth_sendTransaction", [
+          {
+            from,
+            to: contractAddress,
+            data: EXAMPLE_REVERT_CONTRACT.selectors.revertsWithReasonString,
+          },
+        ]);
+      }
+
+      async function getNonce(provider: EthereumProvider, address: string) {
+        return provider.send("eth_getTransactionCount", [address, "latest"]);
+      }
+
+      it("rejects failing transactions of an opted-in sender without mining them", async function () {
+        const blockNumber = await this.provider.send("eth_blockNumber");
+
+        await assert.isRejected(
+          sendRevertingTransaction(this.provider, OPTED_IN_SENDER),
+          "reverted with reason string 'a reason'"
+        );
+
+        assert.equal(await this.provider.send("eth_blockNumber"), blockNumber);
+        assert.equal(await getNonce(this.provider, OPTED_IN_SENDER), "0x0");
+      });
+
+      it("mines failing transactions of other senders", async function () {
+        await assert.isRejected(
+          sendRevertingTransaction(this.provider, OTHER_SENDER),
+          "reverted with reason string 'a reason'"
+        );
+
+        assert.equal(await getNonce(this.provider, OTHER_SENDER), "0x1");
+      });
+
+      it("forgets the opted-in senders after a reset", async function () {
+        await this.provider.send("hardhat_reset");
+        contractAddress = await deployContract(
+          this.provider,
+          `0x${EXAMPLE_REVERT_CONTRACT.bytecode.object}`
+        );
+
+        await assert.isRejected(
+          sendRevertingTransaction(this.provider, OPTED_IN_SENDER),
+          "reverted with reason string 'a reason'"
+        );
+
+        assert.equal(await getNonce(this.provider, OPTED_IN_SENDER), "0x1");
+      });
+    });
+  });
+});
@@ -0,0 +1,65 @@
+import { assert } from "chai";
+
+import {
+  bufferToRpcData,
+  numberToRpcQuantity,
+} from "../../../../../../src/internal/core/jsonrpc/types/base-types";
+import { assertInvalidInputError } from "../../../helpers/assertions";
+import {
+  BLOCK_NUMBER_OF_10496585,
+  FIRST_TX_HASH_OF_10496585,
+} from "../../../helpers/constants";
+import { FORKED_PROVIDERS, PROVIDERS } from "../../../helpers/providers";
+
+const REMOTE_TX_HASH = bufferToRpcData(FIRST_TX_HASH_OF_10496585);
+
+describe("hardhat_setRemoteTransactionLookup", function () {
+  PROVIDERS.filter(({ isFork }) => !isFork).forEach(({ name, useProvider }) => {
+    describe(`${name} provider`, function () {
+      useProvider();
+
+      it("can't be enabled without forking", async function () {
+        await assertInvalidInputError(
+          this.provider,
+          "hardhat_setRemoteTransactionLookup",
+          [true],
+          "can only be enabled when forking"
+        );
+      });
+    });
+  });
+
+  FORKED_PROVIDERS.forEach(({ rpcProvider, useProvider }) => {
+    describe(`Forked provider (${rpcProvider})`, function () {
+      // The transaction is mined after the fork block
+      useProvider({ forkBlockNumber: Number(BLOCK_NUMBER_OF_10496585) - 1 });
+
+      it("doesn't look up transactions by default", async function () {
+        assert.isNull(
+          await this.provider.send("eth_getTransactionByHash", [
+            REMOTE_TX_HASH,
+          ])
+        );
+      });
+
+      it("looks up transactions and receipts in the forked network", async function () {
+        await this.provider.send("hardhat_setRemoteTransactionLookup", [true]);
+
+        const tx = await this.provider.send("eth_getTransactionByHash", [
+          REMOTE_TX_HASH,
+        ]);
+        assert.equal(tx.hash, REMOTE_TX_HASH);
+        assert.isTrue(tx.remote);
+
+        const receipt = await this.provider.send("eth_getTransactionReceipt", [
+          REMOTE_TX_HASH,
+        ]);
+        assert.equal(
+          receipt.blockNumber,
+          numberToRpcQuantity(BLOCK_NUMBER_OF_10496585)
+        );
+        assert.isTrue(receipt.remote);
+      });
+    });
+  });
+});
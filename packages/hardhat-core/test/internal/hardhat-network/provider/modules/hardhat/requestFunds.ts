@@ -0,0 +1,140 @@
+import { assert } from "chai";
+
+import { numberToRpcQuantity } from "../../../../../../src/internal/core/jsonrpc/types/base-types";
+import { EthereumProvider } from "../../../../../../src/types";
+import { assertInvalidInputError } from "../../../helpers/assertions";
+import {
+  DEFAULT_ACCOUNTS_ADDRESSES,
+  PROVIDERS,
+} from "../../../helpers/providers";
+
+const ADDRESS = "0x0d2026b3ee6ec71fc6746adb6311f6d3ba1c000b";
+
+async function getBalance(
+  provider: EthereumProvider,
+  address = ADDRESS
+): Promise<bigint> {
+  return BigInt(await provider.send("eth_getBalance", [address]));
+}
+
+describe("hardhat_requestFunds", function () {
+  PROVIDERS.forEach(({ name, useProvider }) => {
+    describe(`${name} provider`, function () {
+      describe("without a rate limit", function () {
+        useProvider();
+
+        it("adds the amount to the balance", async function () {
+          await this.provider.send("hardhat_requestFunds", [
+            ADDRESS,
+            numberToRpcQuantity(100),
+          ]);
+          await this.provider.send("hardhat_requestFunds", [
+            ADDRESS,
+            numberToRpcQuantity(50),
+          ]);
+
+          assert.equal(await getBalance(this.provider), 150n);
+        });
+
+        it("handles concurrent requests", async function () {
+          await Promise.all(
+            [1, 2, 3].map((amount) =>
+              this.provider.send("hardhat_requestFunds", [
+                ADDRESS,
+                numberToRpcQuantity(amount),
+              ])
+            )
+          );
+
+          assert.equal(await getBalance(this.provider), 6n);
+        });
+
+        it("allows hardhat_setBalance", async function () {
+          await this.provider.send("hardhat_setBalance", [
+            ADDRESS,
+            numberToRpcQuantity(1),
+          ]);
+
+          assert.equal(await getBalance(this.provider), 1n);
+        });
+
+        it("doesn't count pending transactions twice", async function () {
+          const sender = DEFAULT_ACCOUNTS_ADDRESSES[1];
+          const initialBalance = await getBalance(this.provider, sender);
+
+          await this.provider.send("evm_setAutomine", [false]);
+          const txHash = await this.provider.send("eth_sendTransaction", [
+            {
+              from: sender,
+              to: ADDRESS,
+              value: numberToRpcQuantity(1000),
+            },
+          ]);
+
+          await this.provider.send("hardhat_requestFunds", [
+            sender,
+            numberToRpcQuantity(100),
+          ]);
+          await this.provider.send("evm_mine");
+
+          const { gasUsed, effectiveGasPrice } = await this.provider.send(
+            "eth_getTransactionReceipt",
+            [txHash]
+          );
+          const fee = BigInt(gasUsed) * BigInt(effectiveGasPrice);
+
+          assert.equal(
+            await getBalance(this.provider, sender),
+            initialBalance - 1000n - fee + 100n
+          );
+        });
+      });
+
+      describe("with a rate limit", function () {
+        useProvider({ faucetRateLimit: { maxAmount: 100n, interval: 3600 } });
+
+        it("rejects requests that exceed the limit", async function () {
+          await this.provider.send("hardhat_requestFunds", [
+            ADDRESS,
+            numberToRpcQuantity(60),
+          ]);
+
+          await assertInvalidInputError(
+            this.provider,
+            "hardhat_requestFunds",
+            [ADDRESS, numberToRpcQuantity(41)],
+            "Faucet limit exceeded"
+          );
+
+          assert.equal(await getBalance(this.provider), 60n);
+        });
+
+        it("doesn't let concurrent requests exceed the limit", async function () {
+          const results = await Promise.allSettled(
+            [60, 60].map((amount) =>
+              this.provider.send("hardhat_requestFunds", [
+                ADDRESS,
+                numberToRpcQuantity(amount),
+              ])
+            )
+          );
+
+          assert.deepEqual(
+            results.map(({ status }) => status),
+            ["fulfilled", "rejected"]
+          );
+          assert.equal(await getBalance(this.provider), 60n);
+        });
+
+        it("doesn't limit hardhat_setBalance", async function () {
+          await this.provider.send("hardhat_setBalance", [
+            ADDRESS,
+            numberToRpcQuantity(1000),
+          ]);
+
+          assert.equal(await getBalance(this.provider), 1000n);
+        });
+      });
+    });
+  });
+});
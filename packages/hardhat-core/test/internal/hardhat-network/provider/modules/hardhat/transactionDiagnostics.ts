@@ -0,0 +1,58 @@
+import { assert } from "chai";
+
+import { assertInvalidArgumentsError } from "../../../helpers/assertions";
+import {
+  DEFAULT_ACCOUNTS_ADDRESSES,
+  PROVIDERS,
+} from "../../../helpers/providers";
+
+// PUSH1 0x01 PUSH2 0x0400 MSTORE STOP, which expands the memory to 0x420 bytes
+const MEMORY_EXPANDING_INIT_CODE = "0x60016104005200";
+
+describe("hardhat_getTransactionDiagnostics", function () {
+  PROVIDERS.forEach(({ name, useProvider }) => {
+    describe(`${name} provider`, function () {
+      useProvider();
+
+      it("measures the call depth and memory of a transaction", async function () {
+        const txHash = await this.provider.send("eth_sendTransaction", [
+          {
+            from: DEFAULT_ACCOUNTS_ADDRESSES[1],
+            data: MEMORY_EXPANDING_INIT_CODE,
+          },
+        ]);
+
+        assert.deepEqual(
+          await this.provider.send("hardhat_getTransactionDiagnostics", [
+            txHash,
+          ]),
+          { maxCallDepth: 1, maxCallFanOut: 0, maxMemorySize: 0x420 }
+        );
+      });
+
+      it("returns zeros for transactions that don't run code", async function () {
+        const txHash = await this.provider.send("eth_sendTransaction", [
+          {
+            from: DEFAULT_ACCOUNTS_ADDRESSES[1],
+            to: DEFAULT_ACCOUNTS_ADDRESSES[2],
+          },
+        ]);
+
+        assert.deepEqual(
+          await this.provider.send("hardhat_getTransactionDiagnostics", [
+            txHash,
+          ]),
+          { maxCallDepth: 0, maxCallFanOut: 0, maxMemorySize: 0 }
+        );
+      });
+
+      it("rejects invalid hashes", async function () {
+        await assertInvalidArgumentsError(
+          this.provider,
+          "hardhat_getTransactionDiagnostics",
+          ["0x1234"]
+        );
+      });
+    });
+  });
+});
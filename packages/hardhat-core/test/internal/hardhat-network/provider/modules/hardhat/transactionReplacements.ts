@@ -0,0 +1,112 @@
+import { assert } from "chai";
+
+import { numberToRpcQuantity } from "../../../../../../src/internal/core/jsonrpc/types/base-types";
+import { EthereumProvider } from "../../../../../../src/types";
+import { assertInvalidInputError } from "../../../helpers/assertions";
+import {
+  DEFAULT_ACCOUNTS_ADDRESSES,
+  PROVIDERS,
+} from "../../../helpers/providers";
+
+const SENDER = DEFAULT_ACCOUNTS_ADDRESSES[1];
+const RECIPIENT = DEFAULT_ACCOUNTS_ADDRESSES[2];
+
+describe("Transaction replacements", function () {
+  PROVIDERS.forEach(({ name, useProvider }) => {
+    describe(`${name} provider`, function () {
+      useProvider();
+
+      async function sendTransaction(
+        provider: EthereumProvider,
+        maxFeePerGas: bigint
+      ): Promise<string> {
+        return provider.send("eth_sendTransaction", [
+          {
+            from: SENDER,
+            to: RECIPIENT,
+            nonce: numberToRpcQuantity(0),
+            gas: numberToRpcQuantity(21_000),
+            maxFeePerGas: numberToRpcQuantity(maxFeePerGas),
+            maxPriorityFeePerGas: numberToRpcQuantity(maxFeePerGas / 10n),
+          },
+        ]);
+      }
+
+      describe("with automine disabled", function () {
+        let replacedTxHash: string;
+        let replacementTxHash: string;
+
+        beforeEach(async function () {
+          await this.provider.send("evm_setAutomine", [false]);
+
+          replacedTxHash = await sendTransaction(this.provider, 10n ** 10n);
+          replacementTxHash = await sendTransaction(
+            this.provider,
+            2n * 10n ** 10n
+          );
+        });
+
+        it("links the replaced transaction to its replacement", async function () {
+          assert.equal(
+            await this.provider.send("hardhat_getTransactionReplacement", [
+              replacedTxHash,
+            ]),
+            replacementTxHash
+          );
+          assert.isNull(
+            await this.provider.send("hardhat_getTransactionReplacement", [
+              replacementTxHash,
+            ])
+          );
+        });
+
+        it("returns null for the receipt of the replaced transaction by default", async function () {
+          await this.provider.send("evm_mine");
+
+          assert.isNull(
+            await this.provider.send("eth_getTransactionReceipt", [
+              replacedTxHash,
+            ])
+          );
+        });
+
+        it("throws for the receipt of the replaced transaction if enabled", async function () {
+          await this.provider.send("hardhat_setReplacedTransactionErrors", [
+            true,
+          ]);
+          await this.provider.send("evm_mine");
+
+          await assertInvalidInputError(
+            this.provider,
+            "eth_getTransactionReceipt",
+            [replacedTxHash],
+            `was replaced by transaction ${replacementTxHash}`
+          );
+        });
+
+        it("forgets the replacements after a reset", async function () {
+          await this.provider.send("hardhat_reset");
+
+          assert.isNull(
+            await this.provider.send("hardhat_getTransactionReplacement", [
+              replacedTxHash,
+            ])
+          );
+        });
+      });
+
+      it("doesn't track transactions with automine enabled", async function () {
+        const txHash = await sendTransaction(this.provider, 10n ** 10n);
+
+        assert.isNotNull(
+          await this.provider.send("eth_getTransactionReceipt", [txHash])
+        );
+        assert.isNull(
+          await this.provider.send("hardhat_getTransactionReplacement", [
+            txHash,
+          ])
+        );
+      });
+    });
+  });
+});
@@ -0,0 +1,76 @@
+import { assert } from "chai";
+
+import { EthereumProvider, EthSubscription } from "../../../../../../src/types";
+import {
+  DEFAULT_ACCOUNTS_ADDRESSES,
+  PROVIDERS,
+} from "../../../helpers/providers";
+
+const TAG = { test: "transaction tags" };
+
+describe("Transaction tags", function () {
+  PROVIDERS.forEach(({ name, useProvider, isJsonRpc }) => {
+    describe(`${name} provider`, function () {
+      useProvider();
+
+      async function sendTaggedTransaction(provider: EthereumProvider) {
+        return provider.send("eth_sendTransaction", [
+          {
+            from: DEFAULT_ACCOUNTS_ADDRESSES[1],
+            to: DEFAULT_ACCOUNTS_ADDRESSES[2],
+            tag: TAG,
+          },
+        ]);
+      }
+
+      it("adds the tag to the transaction and its receipt", async function () {
+        const txHash = await sendTaggedTransaction(this.provider);
+
+        const tx = await this.provider.send("eth_getTransactionByHash", [
+          txHash,
+        ]);
+        const receipt = await this.provider.send("eth_getTransactionReceipt", [
+          txHash,
+        ]);
+
+        assert.deepEqual(tx.tag, TAG);
+        assert.deepEqual(receipt.tag, TAG);
+      });
+
+      it("adds the tag to pending transactions", async function () {
+        await this.provider.send("evm_setAutomine", [false]);
+        await sendTaggedTransaction(this.provider);
+
+        const [tx] = await this.provider.send("eth_pendingTransactions");
+        assert.deepEqual(tx.tag, TAG);
+      });
+
+      if (!isJsonRpc) {
+        it("adds the tag to full pending transaction notifications", async function () {
+          await this.provider.send("evm_setAutomine", [false]);
+          const subscription = await this.provider.send("eth_subscribe", [
+            "newPendingTransactions",
+            true,
+          ]);
+
+          const notification = new Promise<any>((resolve) => {
+            this.hardhatNetworkProvider.on(
+              "message",
+              ({ data }: EthSubscription) => {
+                if (data.subscription === subscription) {
+                  resolve(data.result);
+                }
+              }
+            );
+          });
+
+          const txHash = await sendTaggedTransaction(this.provider);
+          const tx = await notification;
+
+          assert.equal(tx.hash, txHash);
+          assert.deepEqual(tx.tag, TAG);
+        });
+      }
+    });
+  });
+});
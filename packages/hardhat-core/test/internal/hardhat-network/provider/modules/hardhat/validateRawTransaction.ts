@@ -0,0 +1,204 @@
+import { assert } from "chai";
+import { FeeMarketEIP1559Transaction } from "@nomicfoundation/ethereumjs-tx";
+import { toBytes } from "@nomicfoundation/ethereumjs-util";
+
+import {
+  bufferToRpcData,
+  numberToRpcQuantity,
+} from "../../../../../../src/internal/core/jsonrpc/types/base-types";
+import { EIP1193Provider } from "../../../../../../src/types";
+import { assertInvalidInputError } from "../../../helpers/assertions";
+import { getPendingBaseFeePerGas } from "../../../helpers/getPendingBaseFeePerGas";
+import {
+  DEFAULT_ACCOUNTS,
+  DEFAULT_ACCOUNTS_ADDRESSES,
+  PROVIDERS,
+} from "../../../helpers/providers";
+import { retrieveCommon } from "../../../helpers/retrieveCommon";
+
+const RECIPIENT = DEFAULT_ACCOUNTS_ADDRESSES[2];
+
+describe("hardhat_validateRawTransaction", function () {
+  PROVIDERS.forEach(({ name, useProvider }) => {
+    describe(`${name} provider`, function () {
+      useProvider();
+
+      async function signTransaction(
+        hardhatNetworkProvider: EIP1193Provider,
+        txData: {
+          nonce: bigint;
+          maxFeePerGas: bigint;
+          maxPriorityFeePerGas?: bigint;
+        }
+      ): Promise<string> {
+        const tx = FeeMarketEIP1559Transaction.fromTxData(
+          {
+            maxPriorityFeePerGas: 1n,
+            ...txData,
+            gasLimit: 21_000n,
+            to: RECIPIENT,
+            value: 1n,
+          },
+          { common: await retrieveCommon(hardhatNetworkProvider) }
+        );
+
+        const signedTx = tx.sign(toBytes(DEFAULT_ACCOUNTS[1].privateKey));
+        return bufferToRpcData(Buffer.from(signedTx.serialize()));
+      }
+
+      it("accepts a transaction that the node mines", async function () {
+        const maxFeePerGas =
+          2n * (await getPendingBaseFeePerGas(this.provider));
+        const rawTx = await signTransaction(this.hardhatNetworkProvider, {
+          nonce: 0n,
+          maxFeePerGas,
+        });
+
+        const verdict = await this.provider.send(
+          "hardhat_validateRawTransaction",
+          [rawTx]
+        );
+
+        assert.isTrue(verdict.accepted);
+        assert.deepEqual(verdict.errors, []);
+        assert.equal(verdict.gasEstimate, numberToRpcQuantity(21_000));
+
+        const txHash = await this.provider.send("eth_sendRawTransaction", [
+          rawTx,
+        ]);
+        assert.equal(txHash, verdict.hash);
+      });
+
+      it("rejects a transaction with a future nonce when automining", async function () {
+        const maxFeePerGas =
+          2n * (await getPendingBaseFeePerGas(this.provider));
+        const rawTx = await signTransaction(this.hardhatNetworkProvider, {
+          nonce: 1n,
+          maxFeePerGas,
+        });
+
+        const verdict = await this.provider.send(
+          "hardhat_validateRawTransaction",
+          [rawTx]
+        );
+
+        assert.isFalse(verdict.accepted);
+        assert.include(verdict.errors[0], "Nonce too high");
+
+        await assertInvalidInputError(
+          this.provider,
+          "eth_sendRawTransaction",
+          [rawTx],
+          "Nonce too high"
+        );
+      });
+
+      it("reports a transaction with a future nonce as queued without automine", async function () {
+        await this.provider.send("evm_setAutomine", [false]);
+
+        const maxFeePerGas =
+          2n * (await getPendingBaseFeePerGas(this.provider));
+        const rawTx = await signTransaction(this.hardhatNetworkProvider, {
+          nonce: 1n,
+          maxFeePerGas,
+        });
+
+        const verdict = await this.provider.send(
+          "hardhat_validateRawTransaction",
+          [rawTx]
+        );
+
+        assert.isTrue(verdict.accepted);
+        assert.isTrue(verdict.queued);
+        assert.isUndefined(verdict.gasEstimate);
+      });
+
+      it("rejects a transaction that can't pay the next base fee when automining", async function () {
+        const baseFee = await getPendingBaseFeePerGas(this.provider);
+        const rawTx = await signTransaction(this.hardhatNetworkProvider, {
+          nonce: 0n,
+          maxFeePerGas: baseFee - 1n,
+        });
+
+        const verdict = await this.provider.send(
+          "hardhat_validateRawTransaction",
+          [rawTx]
+        );
+
+        assert.isFalse(verdict.accepted);
+        assert.include(verdict.errors[0], "too low for the next block");
+
+        await assertInvalidInputError(
+          this.provider,
+          "eth_sendRawTransaction",
+          [rawTx],
+          "too low for the next block"
+        );
+      });
+
+      describe("replacements", function () {
+        let maxFeePerGas: bigint;
+        let pendingTxHash: string;
+
+        beforeEach(async function () {
+          await this.provider.send("evm_setAutomine", [false]);
+
+          maxFeePerGas = 2n * (await getPendingBaseFeePerGas(this.provider));
+          pendingTxHash = await this.provider.send("eth_sendRawTransaction", [
+            await signTransaction(this.hardhatNetworkProvider, {
+              nonce: 0n,
+              maxFeePerGas,
+            }),
+          ]);
+        });
+
+        it("accepts a replacement that pays enough", async function () {
+          const rawTx = await signTransaction(this.hardhatNetworkProvider, {
+            nonce: 0n,
+            maxFeePerGas: 2n * maxFeePerGas,
+            maxPriorityFeePerGas: 2n,
+          });
+
+          const verdict = await this.provider.send(
+            "hardhat_validateRawTransaction",
+            [rawTx]
+          );
+
+          assert.isTrue(verdict.accepted);
+          assert.equal(verdict.replaces, pendingTxHash);
+          assert.equal(verdict.gasEstimate, numberToRpcQuantity(21_000));
+
+          const txHash = await this.provider.send("eth_sendRawTransaction", [
+            rawTx,
+          ]);
+          assert.equal(txHash, verdict.hash);
+        });
+
+        it("rejects an underpriced replacement", async function () {
+          const rawTx = await signTransaction(this.hardhatNetworkProvider, {
+            nonce: 0n,
+            maxFeePerGas: 2n * maxFeePerGas,
+          });
+
+          const verdict = await this.provider.send(
+            "hardhat_validateRawTransaction",
+            [rawTx]
+          );
+
+          assert.isFalse(verdict.accepted);
+          assert.include(
+            verdict.errors[0],
+            "Replacement transaction underpriced"
+          );
+
+          await assertInvalidInputError(
+            this.provider,
+            "eth_sendRawTransaction",
+            [rawTx],
+            "Replacement transaction underpriced"
+          );
+        });
+      });
+    });
+  });
+});
@@ -0,0 +1,101 @@
+import { assert } from "chai";
+
+import { numberToRpcQuantity } from "../../../../../../src/internal/core/jsonrpc/types/base-types";
+import { assertInvalidArgumentsError } from "../../../helpers/assertions";
+import {
+  DEFAULT_ACCOUNTS_ADDRESSES,
+  PROVIDERS,
+} from "../../../helpers/providers";
+
+describe("trace_block", function () {
+  PROVIDERS.forEach(({ name, useProvider }) => {
+    describe(`${name} provider`, function () {
+      useProvider();
+
+      let txHashes: string[];
+
+      beforeEach(async function () {
+        await this.provider.send("evm_setAutomine", [false]);
+
+        txHashes = [];
+        for (const nonce of [0, 1]) {
+          txHashes.push(
+            await this.provider.send("eth_sendTransaction", [
+              {
+                from: DEFAULT_ACCOUNTS_ADDRESSES[1],
+                to: DEFAULT_ACCOUNTS_ADDRESSES[2],
+                nonce: numberToRpcQuantity(nonce),
+                gas: numberToRpcQuantity(21_000),
+              },
+            ])
+          );
+        }
+
+        await this.provider.send("evm_mine");
+      });
+
+      it("returns the flat traces of the transactions of a block", async function () {
+        const { hash, number } = await this.provider.send(
+          "eth_getBlockByNumber",
+          ["latest", false]
+        );
+
+        const traces = await this.provider.send("trace_block", ["latest"]);
+
+        // Blocks after the merge don't have a reward trace
+        assert.deepEqual(
+          traces.map((trace: any) => [
+            trace.transactionHash,
+            trace.transactionPosition,
+          ]),
+          [
+            [txHashes[0], 0],
+            [txHashes[1], 1],
+          ]
+        );
+
+        for (const trace of traces) {
+          assert.equal(trace.type, "call");
+          assert.equal(trace.blockHash, hash);
+          assert.equal(trace.blockNumber, Number(number));
+        }
+      });
+
+      it("rejects the pending block", async function () {
+        await assertInvalidArgumentsError(
+          this.provider,
+          "trace_block",
+          ["pending"],
+          "doesn't support the pending block"
+        );
+      });
+
+      it("returns null if the block doesn't exist", async function () {
+        assert.isNull(await this.provider.send("trace_block", ["0x100"]));
+      });
+    });
+  });
+
+  describe("before the merge", function () {
+    PROVIDERS[0].useProvider({ hardfork: "london" });
+
+    it("includes the reward of the block", async function () {
+      await this.provider.send("evm_mine");
+
+      const { miner } = await this.provider.send("eth_getBlockByNumber", [
+        "latest",
+        false,
+      ]);
+
+      const traces = await this.provider.send("trace_block", ["latest"]);
+
+      assert.lengthOf(traces, 1);
+      assert.equal(traces[0].type, "reward");
+      assert.deepEqual(traces[0].action, {
+        author: miner,
+        rewardType: "block",
+        value: numberToRpcQuantity(2n * 10n ** 18n),
+      });
+    });
+  });
+});
@@ -0,0 +1,90 @@
+import { assert } from "chai";
+
+import { numberToRpcQuantity } from "../../../../../../src/internal/core/jsonrpc/types/base-types";
+import {
+  DEFAULT_ACCOUNTS_ADDRESSES,
+  PROVIDERS,
+} from "../../../helpers/providers";
+
+describe("trace_transaction", function () {
+  PROVIDERS.forEach(({ name, useProvider }) => {
+    describe(`${name} provider`, function () {
+      useProvider();
+
+      it("returns the flat trace of a transaction", async function () {
+        const txHash = await this.provider.send("eth_sendTransaction", [
+          {
+            from: DEFAULT_ACCOUNTS_ADDRESSES[1],
+            to: DEFAULT_ACCOUNTS_ADDRESSES[2],
+            value: numberToRpcQuantity(1),
+            gas: numberToRpcQuantity(21_000),
+          },
+        ]);
+
+        const { blockHash, blockNumber } = await this.provider.send(
+          "eth_getTransactionByHash",
+          [txHash]
+        );
+
+        const traces = await this.provider.send("trace_transaction", [txHash]);
+
+        assert.deepEqual(traces, [
+          {
+            type: "call",
+            action: {
+              callType: "call",
+              from: DEFAULT_ACCOUNTS_ADDRESSES[1],
+              to: DEFAULT_ACCOUNTS_ADDRESSES[2],
+              gas: numberToRpcQuantity(21_000),
+              input: "0x",
+              value: numberToRpcQuantity(1),
+            },
+            result: { gasUsed: numberToRpcQuantity(21_000), output: "0x" },
+            subtraces: 0,
+            traceAddress: [],
+            blockHash,
+            blockNumber: Number(blockNumber),
+            transactionHash: txHash,
+            transactionPosition: 0,
+          },
+        ]);
+      });
+
+      it("uses the address of the deployed contract for creations", async function () {
+        // PUSH1 0x00 PUSH1 0x00 RETURN, which deploys an empty contract
+        const txHash = await this.provider.send("eth_sendTransaction", [
+          { from: DEFAULT_ACCOUNTS_ADDRESSES[1], data: "0x60006000f3" },
+        ]);
+
+        const { contractAddress } = await this.provider.send(
+          "eth_getTransactionReceipt",
+          [txHash]
+        );
+
+        const [trace] = await this.provider.send("trace_transaction", [txHash]);
+
+        assert.equal(trace.type, "create");
+        assert.equal(trace.action.creationMethod, "create");
+        assert.equal(trace.result.address, contractAddress);
+      });
+
+      it("returns null for pending and unknown transactions", async function () {
+        await this.provider.send("evm_setAutomine", [false]);
+
+        const txHash = await this.provider.send("eth_sendTransaction", [
+          {
+            from: DEFAULT_ACCOUNTS_ADDRESSES[1],
+            to: DEFAULT_ACCOUNTS_ADDRESSES[2],
+          },
+        ]);
+
+        assert.isNull(await this.provider.send("trace_transaction", [txHash]));
+        assert.isNull(
+          await this.provider.send("trace_transaction", [
+            `0x${"11".repeat(32)}`,
+          ])
+        );
+      });
+    });
+  });
+});
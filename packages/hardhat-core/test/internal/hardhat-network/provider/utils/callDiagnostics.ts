@@ -0,0 +1,80 @@
+import { assert } from "chai";
+
+import { RpcStructLog } from "../../../../../src/internal/hardhat-network/provider/output";
+import { computeCallDiagnostics } from "../../../../../src/internal/hardhat-network/provider/utils/callDiagnostics";
+
+function step(op: string, depth: number, memoryWords = 0): RpcStructLog {
+  return {
+    depth,
+    gas: 100_000,
+    gasCost: 0,
+    op,
+    pc: 0,
+    memory: new Array(memoryWords).fill("00".repeat(32)),
+  };
+}
+
+function trace(structLogs: RpcStructLog[]) {
+  return { failed: false, gas: 100_000, returnValue: "", structLogs };
+}
+
+describe("Call diagnostics", function () {
+  it("returns zeros for transactions that don't run code", function () {
+    assert.deepEqual(computeCallDiagnostics(trace([])), {
+      maxCallDepth: 0,
+      maxCallFanOut: 0,
+      maxMemorySize: 0,
+    });
+  });
+
+  it("measures the depth, fan-out and memory of the call frames", function () {
+    const diagnostics = computeCallDiagnostics(
+      trace([
+        step("CALL", 1),
+        step("CALL", 2, 1),
+        step("STOP", 3, 4),
+        step("STATICCALL", 2, 2),
+        step("RETURN", 3),
+        step("STOP", 2, 2),
+        step("CREATE", 1, 1),
+        step("STOP", 2),
+        step("STOP", 1, 1),
+      ])
+    );
+
+    assert.deepEqual(diagnostics, {
+      maxCallDepth: 3,
+      // The second frame made two calls, and the first one made a call
+      // and a contract creation
+      maxCallFanOut: 2,
+      maxMemorySize: 128,
+    });
+  });
+
+  it("uses the memory size of the steps without memory", function () {
+    const diagnostics = computeCallDiagnostics(
+      trace([
+        { depth: 1, gas: 100_000, gasCost: 0, op: "MSTORE", pc: 0, memSize: 0 },
+        { depth: 1, gas: 100_000, gasCost: 0, op: "STOP", pc: 1, memSize: 96 },
+      ])
+    );
+
+    assert.equal(diagnostics.maxMemorySize, 96);
+  });
+
+  it("counts the calls of each frame separately", function () {
+    const diagnostics = computeCallDiagnostics(
+      trace([
+        step("CALL", 1),
+        step("CALL", 2),
+        step("STOP", 2),
+        step("CALL", 1),
+        step("CALL", 2),
+        step("STOP", 2),
+        step("STOP", 1),
+      ])
+    );
+
+    assert.equal(diagnostics.maxCallFanOut, 2);
+  });
+});
@@ -0,0 +1,158 @@
+import { assert } from "chai";
+
+import {
+  RpcDebugTraceOutput,
+  RpcStructLog,
+} from "../../../../../src/internal/hardhat-network/provider/output";
+import {
+  CallExecution,
+  diffCallExecutions,
+  summarizeCallTrace,
+} from "../../../../../src/internal/hardhat-network/provider/utils/callDiff";
+
+const CONTRACT = "0x5fbdb2315678afecb367f032d93f642f64180aa3";
+const OTHER_CONTRACT = "0xe7f1725e7734ce288f8367e1bb143e90bb3f0512";
+
+function step(
+  op: string,
+  depth: number,
+  stack: string[],
+  memory?: string[]
+): RpcStructLog {
+  return { depth, gas: 100_000, gasCost: 3, op, pc: 0, stack, memory };
+}
+
+function trace(
+  structLogs: RpcStructLog[],
+  failed = false
+): RpcDebugTraceOutput {
+  return { failed, gas: 30_000, returnValue: "", structLogs };
+}
+
+function word(value: number): string {
+  return `0x${value.toString(16).padStart(64, "0")}`;
+}
+
+describe("Call diff", function () {
+  describe("summarizeCallTrace", function () {
+    it("collects the logs and storage slots of the call", function () {
+      const execution = summarizeCallTrace(
+        trace([
+          step("SSTORE", 1, ["5", "1"]),
+          step("LOG1", 1, ["aa", "20", "0"], [word(42).slice(2)]),
+          step("SLOAD", 1, ["3"]),
+          step("STOP", 1, ["9"]),
+        ]),
+        CONTRACT
+      );
+
+      assert.deepEqual(execution, {
+        success: true,
+        returnData: "0x",
+        gasUsed: "0x7530",
+        logs: [{ address: CONTRACT, topics: [word(0xaa)], data: word(42) }],
+        storage: { [CONTRACT]: { [word(1)]: word(5), [word(3)]: word(9) } },
+      });
+    });
+
+    it("ignores the effects of the frames that reverted", function () {
+      const execution = summarizeCallTrace(
+        trace([
+          step("SSTORE", 1, ["5", "1"]),
+          step("CALL", 1, ["0", "0", "0", "0", "0", OTHER_CONTRACT, "ffff"]),
+          step("SSTORE", 2, ["7", "2"]),
+          step("REVERT", 2, ["0", "0"]),
+          step("STOP", 1, ["0"]),
+        ]),
+        CONTRACT
+      );
+
+      assert.deepEqual(execution.storage, {
+        [CONTRACT]: { [word(1)]: word(5) },
+      });
+    });
+
+    it("attributes the effects of a creation to the created contract", function () {
+      const execution = summarizeCallTrace(
+        trace([
+          step("CREATE", 1, ["20", "0", "0"]),
+          step("SSTORE", 2, ["1", "0"]),
+          step("LOG0", 2, ["0", "0"]),
+          step("RETURN", 2, ["0", "0"]),
+          step("STOP", 1, [OTHER_CONTRACT]),
+        ]),
+        CONTRACT
+      );
+
+      assert.deepEqual(execution.logs, [
+        { address: OTHER_CONTRACT, topics: [], data: "0x" },
+      ]);
+      assert.deepEqual(execution.storage, {
+        [OTHER_CONTRACT]: { [word(0)]: word(1) },
+      });
+    });
+
+    it("ignores every effect of a failed call", function () {
+      const execution = summarizeCallTrace(
+        trace(
+          [step("SSTORE", 1, ["5", "1"]), step("REVERT", 1, ["0", "0"])],
+          true
+        ),
+        CONTRACT
+      );
+
+      assert.isFalse(execution.success);
+      assert.deepEqual(execution.logs, []);
+      assert.deepEqual(execution.storage, {});
+    });
+  });
+
+  describe("diffCallExecutions", function () {
+    const execution: CallExecution = {
+      success: true,
+      returnData: "0x01",
+      gasUsed: "0x5208",
+      logs: [{ address: CONTRACT, topics: [word(1)], data: "0x" }],
+      storage: { [CONTRACT]: { [word(0)]: word(1) } },
+    };
+
+    it("returns no changes for equal executions", function () {
+      assert.deepEqual(diffCallExecutions(execution, execution).changes, {
+        logs: [],
+        storage: [],
+      });
+    });
+
+    it("returns the fields that changed", function () {
+      const otherLog = { address: OTHER_CONTRACT, topics: [], data: "0x" };
+      const otherExecution: CallExecution = {
+        ...execution,
+        gasUsed: "0x6000",
+        logs: [...execution.logs, otherLog],
+        storage: {
+          [CONTRACT]: { [word(0)]: word(2) },
+          [OTHER_CONTRACT]: { [word(5)]: word(0) },
+        },
+      };
+
+      assert.deepEqual(diffCallExecutions(execution, otherExecution).changes, {
+        gasUsed: { first: "0x5208", second: "0x6000" },
+        logs: [{ index: 1, first: null, second: otherLog }],
+        storage: [
+          {
+            address: CONTRACT,
+            slot: word(0),
+            first: word(1),
+            second: word(2),
+          },
+          {
+            address: OTHER_CONTRACT,
+            slot: word(5),
+            first: null,
+            second: word(0),
+          },
+        ],
+      });
+    });
+  });
+});
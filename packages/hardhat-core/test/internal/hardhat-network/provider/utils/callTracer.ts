@@ -0,0 +1,176 @@
+import { assert } from "chai";
+
+import { RpcStructLog } from "../../../../../src/internal/hardhat-network/provider/output";
+import {
+  computeCallFrames,
+  TracedCall,
+} from "../../../../../src/internal/hardhat-network/provider/utils/callTracer";
+
+const SENDER = "0x000000000000000000000000000000000000000a";
+const CONTRACT = "0x000000000000000000000000000000000000000b";
+const CALLEE = "0x000000000000000000000000000000000000000c";
+const LIBRARY = "0x000000000000000000000000000000000000000d";
+
+const TRACED_CALL: TracedCall = {
+  type: "CALL",
+  from: SENDER,
+  to: CONTRACT,
+  value: "0x0",
+  gas: "0x186a0",
+  input: "0x",
+};
+
+function word(n: number | string): string {
+  const hex = typeof n === "number" ? n.toString(16) : n.replace(/^0x/, "");
+  return hex.padStart(64, "0");
+}
+
+// Returns a step whose stack is given from the top
+function step(
+  op: string,
+  depth: number,
+  gas: number,
+  stackFromTop: Array<number | string> = [],
+  memory: string[] = []
+): RpcStructLog {
+  return {
+    depth,
+    gas,
+    gasCost: 0,
+    op,
+    pc: 0,
+    stack: stackFromTop.map(word).reverse(),
+    memory,
+  };
+}
+
+function trace(structLogs: RpcStructLog[]) {
+  return { failed: false, gas: 50_000, returnValue: "", structLogs };
+}
+
+describe("Call tracer", function () {
+  describe("computeCallFrames", function () {
+    it("returns the traced call as the top-level frame", function () {
+      assert.deepEqual(computeCallFrames(trace([]), TRACED_CALL), {
+        type: "CALL",
+        from: SENDER,
+        to: CONTRACT,
+        value: "0x0",
+        gas: "0x186a0",
+        gasUsed: "0xc350",
+        input: "0x",
+      });
+    });
+
+    it("rebuilds the calls with their input and output", function () {
+      const frame = computeCallFrames(
+        trace([
+          // gas, address, value, argsOffset, argsSize, retOffset, retSize
+          step(
+            "CALL",
+            1,
+            90_000,
+            [30_000, CALLEE, 0, 0, 4, 0, 32],
+            [`a9059cbb${"00".repeat(28)}`]
+          ),
+          step("PUSH1", 2, 30_000),
+          // offset, size
+          step("RETURN", 2, 29_990, [0, 32], [word(42)]),
+          step("STOP", 1, 60_000, [1]),
+        ]),
+        TRACED_CALL
+      );
+
+      assert.deepEqual(frame.calls, [
+        {
+          type: "CALL",
+          from: CONTRACT,
+          to: CALLEE,
+          value: "0x0",
+          gas: "0x7530",
+          gasUsed: "0xa",
+          input: "0xa9059cbb",
+          output: `0x${word(42)}`,
+        },
+      ]);
+    });
+
+    it("includes calls that don't run code and failed calls", function () {
+      const frame = computeCallFrames(
+        trace([
+          step("CALL", 1, 64_000, [0, CALLEE, 1, 0, 0, 0, 0]),
+          // gas, address, argsOffset, argsSize, retOffset, retSize
+          step("STATICCALL", 1, 60_000, [1000, CALLEE, 0, 0, 0, 0]),
+          step("STOP", 1, 50_000, [0]),
+        ]),
+        TRACED_CALL
+      );
+
+      const calls = frame.calls!;
+      assert.lengthOf(calls, 2);
+
+      // The gas of a call that transfers value includes the stipend
+      assert.equal(calls[0].gas, "0x8fc");
+      assert.equal(calls[0].gasUsed, "0x0");
+      assert.isUndefined(calls[0].error);
+
+      assert.equal(calls[1].gas, "0x3e8");
+      assert.equal(calls[1].error, "execution failed");
+    });
+
+    it("uses the address of created contracts and delegated callers", function () {
+      const created = "0x00000000000000000000000000000000000000ee";
+
+      const frame = computeCallFrames(
+        trace([
+          // value, offset, size
+          step("CREATE", 1, 90_000, [0, 0, 4], [`600a600c${"00".repeat(28)}`]),
+          // gas, address, argsOffset, argsSize, retOffset, retSize
+          step("DELEGATECALL", 2, 50_000, [100, LIBRARY, 0, 0, 0, 0]),
+          step("REVERT", 3, 100, [0, 0]),
+          step("STOP", 2, 40_000, [0]),
+          step("STOP", 1, 40_000, [created]),
+        ]),
+        TRACED_CALL
+      );
+
+      assert.deepEqual(frame.calls, [
+        {
+          type: "CREATE",
+          from: CONTRACT,
+          to: created,
+          value: "0x0",
+          gas: "0xc350",
+          gasUsed: "0x2710",
+          input: "0x600a600c",
+          calls: [
+            {
+              type: "DELEGATECALL",
+              from: created,
+              to: LIBRARY,
+              gas: "0x64",
+              gasUsed: "0x0",
+              input: "0x",
+              error: "execution reverted",
+            },
+          ],
+        },
+      ]);
+    });
+
+    it("reports the error of a failed transaction", function () {
+      const frame = computeCallFrames(
+        {
+          failed: true,
+          gas: 50_000,
+          returnValue: "08c379a0",
+          structLogs: [step("REVERT", 1, 1000, [0, 4])],
+        },
+        TRACED_CALL
+      );
+
+      assert.equal(frame.output, "0x08c379a0");
+      assert.equal(frame.error, "execution reverted");
+    });
+  });
+});
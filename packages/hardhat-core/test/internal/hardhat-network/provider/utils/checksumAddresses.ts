@@ -0,0 +1,54 @@
+import { assert } from "chai";
+
+import {
+  checksumAddressesInResult,
+  checksumAddressFields,
+} from "../../../../../src/internal/hardhat-network/provider/utils/checksumAddresses";
+
+const ADDRESS = "0xf39fd6e51aad88f6f4ce6ab8827279cfffb92266";
+const CHECKSUMMED_ADDRESS = "0xf39Fd6e51aad88F6F4ce6aB8827279cffFb92266";
+
+describe("checksumAddressesInResult", () => {
+  it("checksums the result of methods that return an address", () => {
+    assert.strictEqual(
+      checksumAddressesInResult("eth_coinbase", ADDRESS),
+      CHECKSUMMED_ADDRESS
+    );
+  });
+
+  it("checksums the result of methods that return a list of addresses", () => {
+    assert.deepEqual(checksumAddressesInResult("eth_accounts", [ADDRESS]), [
+      CHECKSUMMED_ADDRESS,
+    ]);
+  });
+
+  it("doesn't modify data that isn't in an address field", () => {
+    assert.strictEqual(checksumAddressesInResult("eth_call", ADDRESS), ADDRESS);
+  });
+});
+
+describe("checksumAddressFields", () => {
+  it("checksums nested address fields", () => {
+    const receipt = {
+      from: ADDRESS,
+      to: null,
+      contractAddress: ADDRESS,
+      logs: [{ address: ADDRESS, data: ADDRESS }],
+    };
+
+    assert.deepEqual(checksumAddressFields(receipt), {
+      from: CHECKSUMMED_ADDRESS,
+      to: null,
+      contractAddress: CHECKSUMMED_ADDRESS,
+      logs: [{ address: CHECKSUMMED_ADDRESS, data: ADDRESS }],
+    });
+  });
+
+  it("doesn't modify the original object", () => {
+    const tx = { from: ADDRESS };
+
+    checksumAddressFields(tx);
+
+    assert.strictEqual(tx.from, ADDRESS);
+  });
+});
@@ -0,0 +1,98 @@
+import { assert } from "chai";
+import { Common } from "@nomicfoundation/ethereumjs-common";
+
+import { disassemble } from "../../../../../src/internal/hardhat-network/provider/utils/disassemble";
+
+describe("disassemble", function () {
+  const cancun = new Common({ chain: "mainnet", hardfork: "cancun" });
+  const london = new Common({ chain: "mainnet", hardfork: "london" });
+
+  it("returns an empty disassembly for empty code", function () {
+    assert.deepEqual(disassemble(Buffer.alloc(0), cancun), {
+      instructions: [],
+      basicBlocks: [],
+    });
+  });
+
+  it("includes the data of push instructions", function () {
+    // PUSH1 0x80 PUSH2 0x0102 PUSH0 STOP
+    const code = Buffer.from("60806101025f00", "hex");
+
+    assert.deepEqual(disassemble(code, cancun).instructions, [
+      { pc: 0, opcode: "PUSH1", pushData: "0x80" },
+      { pc: 2, opcode: "PUSH2", pushData: "0x0102" },
+      { pc: 5, opcode: "PUSH0" },
+      { pc: 6, opcode: "STOP" },
+    ]);
+  });
+
+  it("truncates the data of a push at the end of the code", function () {
+    // PUSH4 0x0102
+    const code = Buffer.from("630102", "hex");
+
+    assert.deepEqual(disassemble(code, cancun).instructions, [
+      { pc: 0, opcode: "PUSH4", pushData: "0x0102" },
+    ]);
+  });
+
+  it("only recognizes the opcodes of the given hardfork", function () {
+    // PUSH0 MCOPY TLOAD TSTORE BLOBHASH BLOBBASEFEE BASEFEE
+    const code = Buffer.from("5f5e5c5d494a48", "hex");
+
+    assert.deepEqual(
+      disassemble(code, cancun).instructions.map((i) => i.opcode),
+      [
+        "PUSH0",
+        "MCOPY",
+        "TLOAD",
+        "TSTORE",
+        "BLOBHASH",
+        "BLOBBASEFEE",
+        "BASEFEE",
+      ]
+    );
+
+    assert.deepEqual(
+      disassemble(code, london).instructions.map((i) => i.opcode),
+      [
+        "UNRECOGNIZED_5F",
+        "UNRECOGNIZED_5E",
+        "UNRECOGNIZED_5C",
+        "UNRECOGNIZED_5D",
+        "UNRECOGNIZED_49",
+        "UNRECOGNIZED_4A",
+        "BASEFEE",
+      ]
+    );
+  });
+
+  it("splits the code in basic blocks", function () {
+    // 0: PUSH1 0x06
+    // 2: JUMPI
+    // 3: CALLVALUE
+    // 4: DUP1
+    // 5: JUMPDEST
+    // 6: JUMPDEST
+    // 7: STOP
+    // 8: CALLER
+    const code = Buffer.from("60065734805b5b0033", "hex");
+
+    assert.deepEqual(disassemble(code, cancun).basicBlocks, [
+      { start: 0, end: 2 },
+      { start: 3, end: 4 },
+      { start: 5, end: 5 },
+      { start: 6, end: 7 },
+      { start: 8, end: 8 },
+    ]);
+  });
+
+  it("ends a basic block after an unrecognized opcode", function () {
+    // CALLER UNRECOGNIZED_0C CALLER
+    const code = Buffer.from("330c33", "hex");
+
+    assert.deepEqual(disassemble(code, cancun).basicBlocks, [
+      { start: 0, end: 1 },
+      { start: 2, end: 2 },
+    ]);
+  });
+});
@@ -0,0 +1,74 @@
+import { assert } from "chai";
+
+import { InvalidInputError } from "../../../../../src/internal/core/providers/errors";
+import { FaucetRateLimiter } from "../../../../../src/internal/hardhat-network/provider/utils/faucetRateLimiter";
+
+const ADDRESS = "0x0d2026b3ee6ec71fc6746adb6311f6d3ba1c000b";
+const OTHER_ADDRESS = "0xf39fd6e51aad88f6f4ce6ab8827279cfffb92266";
+
+describe("FaucetRateLimiter", function () {
+  let now: number;
+  let limiter: FaucetRateLimiter;
+
+  beforeEach(function () {
+    now = 1_000_000;
+    limiter = new FaucetRateLimiter(
+      { maxAmount: 100n, interval: 60 },
+      () => now
+    );
+  });
+
+  function request(address: string, amount: bigint) {
+    limiter.assertCanRequest(address, amount);
+    limiter.registerRequest(address, amount);
+  }
+
+  it("accepts requests within the limit", function () {
+    request(ADDRESS, 60n);
+    request(ADDRESS, 40n);
+  });
+
+  it("rejects requests that exceed the limit", function () {
+    request(ADDRESS, 60n);
+
+    assert.throws(
+      () => limiter.assertCanRequest(ADDRESS, 41n),
+      InvalidInputError,
+      "Faucet limit exceeded"
+    );
+  });
+
+  it("doesn't count requests until they are registered", function () {
+    limiter.assertCanRequest(ADDRESS, 100n);
+    limiter.assertCanRequest(ADDRESS, 100n);
+  });
+
+  it("limits each address separately", function () {
+    request(ADDRESS, 100n);
+    request(OTHER_ADDRESS, 100n);
+  });
+
+  it("ignores the case of the address", function () {
+    request(ADDRESS, 100n);
+
+    assert.throws(
+      () => limiter.assertCanRequest(ADDRESS.toUpperCase(), 1n),
+      InvalidInputError
+    );
+  });
+
+  it("accepts new requests once the interval has passed", function () {
+    request(ADDRESS, 100n);
+
+    now += 60_000;
+
+    request(ADDRESS, 100n);
+  });
+
+  it("forgets every request when cleared", function () {
+    request(ADDRESS, 100n);
+    limiter.clear();
+
+    request(ADDRESS, 100n);
+  });
+});
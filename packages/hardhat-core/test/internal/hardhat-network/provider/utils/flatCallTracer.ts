@@ -0,0 +1,208 @@
+import { assert } from "chai";
+
+import { CallFrame } from "../../../../../src/internal/hardhat-network/provider/utils/callTracer";
+import {
+  FlatTraceContext,
+  flattenCallFrames,
+  getBlockRewardTrace,
+  getFlatTraceContext,
+} from "../../../../../src/internal/hardhat-network/provider/utils/flatCallTracer";
+
+const SENDER = "0x000000000000000000000000000000000000000a";
+const CONTRACT = "0x000000000000000000000000000000000000000b";
+const CALLEE = "0x000000000000000000000000000000000000000c";
+const CREATED = "0x000000000000000000000000000000000000000d";
+
+const TX_HASH =
+  "0x09cfae3d6a1a2e3d4d58e3c3bba9e55aedd3a16df2b82f27ef04e4cd8a3a0f1a";
+const BLOCK_HASH =
+  "0x4c2b8bc9ab2e1dd4b0d1f8d5e47f6a4c2bf85d0fd6bd0e62ce2e0a2fe2b69a3d";
+
+const CONTEXT: FlatTraceContext = {
+  blockHash: BLOCK_HASH,
+  blockNumber: 16,
+  transactionHash: TX_HASH,
+  transactionPosition: 1,
+};
+
+describe("Flat call tracer", function () {
+  describe("getFlatTraceContext", function () {
+    it("returns the block and position of the transaction", function () {
+      assert.deepEqual(
+        getFlatTraceContext({
+          blockHash: BLOCK_HASH,
+          blockNumber: "0x10",
+          hash: TX_HASH,
+          transactionIndex: "0x1",
+        }),
+        CONTEXT
+      );
+    });
+  });
+
+  describe("getBlockRewardTrace", function () {
+    it("returns the reward of the miner of the block", function () {
+      assert.deepEqual(
+        getBlockRewardTrace(
+          { hash: BLOCK_HASH, number: "0x10", miner: SENDER },
+          2n * 10n ** 18n
+        ),
+        {
+          type: "reward",
+          action: {
+            author: SENDER,
+            rewardType: "block",
+            value: "0x1bc16d674ec80000",
+          },
+          result: null,
+          subtraces: 0,
+          traceAddress: [],
+          blockHash: BLOCK_HASH,
+          blockNumber: 16,
+        }
+      );
+    });
+  });
+
+  describe("flattenCallFrames", function () {
+    it("returns the frames in order with their trace addresses", function () {
+      const frame: CallFrame = {
+        type: "CALL",
+        from: SENDER,
+        to: CONTRACT,
+        value: "0x1",
+        gas: "0x186a0",
+        gasUsed: "0xc350",
+        input: "0xa9059cbb",
+        output: "0x01",
+        calls: [
+          {
+            type: "STATICCALL",
+            from: CONTRACT,
+            to: CALLEE,
+            gas: "0x3e8",
+            gasUsed: "0x64",
+            input: "0x",
+            calls: [
+              {
+                type: "DELEGATECALL",
+                from: CALLEE,
+                to: CONTRACT,
+                gas: "0x1f4",
+                gasUsed: "0xa",
+                input: "0x",
+              },
+            ],
+          },
+          {
+            type: "CREATE2",
+            from: CONTRACT,
+            to: CREATED,
+            value: "0x0",
+            gas: "0x2710",
+            gasUsed: "0x3e8",
+            input: "0x600a600c",
+            output: "0x00",
+          },
+        ],
+      };
+
+      const traces = flattenCallFrames(frame, CONTEXT);
+
+      assert.deepEqual(
+        traces.map(({ traceAddress, subtraces }) => [traceAddress, subtraces]),
+        [
+          [[], 2],
+          [[0], 1],
+          [[0, 0], 0],
+          [[1], 0],
+        ]
+      );
+
+      assert.deepEqual(traces[0], {
+        type: "call",
+        action: {
+          callType: "call",
+          from: SENDER,
+          to: CONTRACT,
+          gas: "0x186a0",
+          input: "0xa9059cbb",
+          value: "0x1",
+        },
+        result: { gasUsed: "0xc350", output: "0x01" },
+        subtraces: 2,
+        traceAddress: [],
+        ...CONTEXT,
+      });
+
+      assert.equal(traces[1].action.value, "0x0");
+
+      assert.deepEqual(traces[3].action, {
+        creationMethod: "create2",
+        from: CONTRACT,
+        gas: "0x2710",
+        init: "0x600a600c",
+        value: "0x0",
+      });
+      assert.deepEqual(traces[3].result, {
+        address: CREATED,
+        code: "0x00",
+        gasUsed: "0x3e8",
+      });
+    });
+
+    it("leaves out the context if it isn't given", function () {
+      const [trace] = flattenCallFrames({
+        type: "CALL",
+        from: SENDER,
+        to: CONTRACT,
+        gasUsed: "0x0",
+        input: "0x",
+      });
+
+      assert.notProperty(trace, "blockHash");
+      assert.notProperty(trace, "transactionHash");
+    });
+
+    it("uses OpenEthereum's errors and keeps the output of reverts", function () {
+      const [reverted, outOfGas, invalidOpcode] = flattenCallFrames({
+        type: "CALL",
+        from: SENDER,
+        to: CONTRACT,
+        gasUsed: "0x64",
+        input: "0x",
+        output: "0x08c379a0",
+        error: "execution reverted",
+        calls: [
+          {
+            type: "CALL",
+            from: CONTRACT,
+            to: CALLEE,
+            gasUsed: "0x3e8",
+            input: "0x",
+            error: "out of gas",
+          },
+          {
+            type: "CALL",
+            from: CONTRACT,
+            to: CALLEE,
+            gasUsed: "0x3e8",
+            input: "0x",
+            error: "invalid opcode: INVALID",
+          },
+        ],
+      });
+
+      assert.equal(reverted.error, "Reverted");
+      assert.deepEqual(reverted.result, {
+        gasUsed: "0x64",
+        output: "0x08c379a0",
+      });
+
+      assert.equal(outOfGas.error, "Out of gas");
+      assert.isUndefined(outOfGas.result);
+
+      assert.equal(invalidOpcode.error, "Bad instruction");
+    });
+  });
+});
@@ -0,0 +1,71 @@
+import { assert } from "chai";
+
+import { RpcStructLog } from "../../../../../src/internal/hardhat-network/provider/output";
+import { computeFourByteStatistics } from "../../../../../src/internal/hardhat-network/provider/utils/fourByteTracer";
+
+const CALLEE =
+  "000000000000000000000000000000000000000000000000000000000000beef";
+const PRECOMPILE =
+  "0000000000000000000000000000000000000000000000000000000000000002";
+
+function word(n: number): string {
+  return n.toString(16).padStart(64, "0");
+}
+
+// Returns a step of an opcode whose stack is given from the top
+function step(op: string, stackFromTop: string[]): RpcStructLog {
+  return {
+    depth: 1,
+    gas: 100_000,
+    gasCost: 0,
+    op,
+    pc: 0,
+    stack: [...stackFromTop].reverse(),
+    memory: [`a9059cbb${"00".repeat(28)}`, word(0)],
+  };
+}
+
+describe("4byte tracer", function () {
+  describe("computeFourByteStatistics", function () {
+    it("counts the input and the calls per selector and calldata size", function () {
+      const statistics = computeFourByteStatistics(
+        {
+          failed: false,
+          gas: 50_000,
+          returnValue: "",
+          structLogs: [
+            // gas, address, value, argsOffset, argsSize, ...
+            step("CALL", [word(1000), CALLEE, word(0), word(0), word(68)]),
+            // gas, address, argsOffset, argsSize, ...
+            step("STATICCALL", [word(1000), CALLEE, word(0), word(36)]),
+            step("DELEGATECALL", [word(1000), CALLEE, word(0), word(36)]),
+            step("STOP", []),
+          ],
+        },
+        "0xa9059cbb0000000000000000000000000000000000000000000000000000000000000001"
+      );
+
+      assert.deepEqual(statistics, {
+        "0xa9059cbb-32": 3,
+        "0xa9059cbb-64": 1,
+      });
+    });
+
+    it("doesn't count contract creations, precompiles and calls without a selector", function () {
+      const statistics = computeFourByteStatistics(
+        {
+          failed: false,
+          gas: 50_000,
+          returnValue: "",
+          structLogs: [
+            step("STATICCALL", [word(1000), PRECOMPILE, word(0), word(36)]),
+            step("CALL", [word(1000), CALLEE, word(0), word(0), word(3)]),
+          ],
+        },
+        undefined
+      );
+
+      assert.deepEqual(statistics, {});
+    });
+  });
+});
@@ -0,0 +1,71 @@
+import { assert } from "chai";
+
+import { FullTransactionFilters } from "../../../../../src/internal/hardhat-network/provider/utils/fullTransactionFilters";
+
+describe("FullTransactionFilters", function () {
+  let filters: FullTransactionFilters;
+
+  beforeEach(function () {
+    filters = new FullTransactionFilters();
+  });
+
+  describe("extractFlag", function () {
+    it("removes the flag from eth_newPendingTransactionFilter", function () {
+      assert.deepEqual(
+        filters.extractFlag("eth_newPendingTransactionFilter", [true]),
+        [[], true]
+      );
+      assert.deepEqual(
+        filters.extractFlag("eth_newPendingTransactionFilter", [false]),
+        [[], false]
+      );
+    });
+
+    it("removes the flag from newPendingTransactions subscriptions", function () {
+      assert.deepEqual(
+        filters.extractFlag("eth_subscribe", ["newPendingTransactions", true]),
+        [["newPendingTransactions"], true]
+      );
+    });
+
+    it("leaves other requests untouched", function () {
+      const newHeads = ["newHeads", true];
+      assert.deepEqual(filters.extractFlag("eth_subscribe", newHeads), [
+        newHeads,
+        false,
+      ]);
+
+      const invalidFlag = ["yes"];
+      assert.deepEqual(
+        filters.extractFlag("eth_newPendingTransactionFilter", invalidFlag),
+        [invalidFlag, false]
+      );
+
+      assert.deepEqual(filters.extractFlag("eth_getFilterChanges", ["0x1"]), [
+        ["0x1"],
+        false,
+      ]);
+    });
+  });
+
+  it("keeps the ids of the filters", function () {
+    filters.add("0xA");
+
+    assert.isTrue(filters.has("0xa"));
+    assert.isFalse(filters.has("0xb"));
+    assert.isFalse(filters.has(10));
+
+    filters.remove("0xa");
+    assert.isFalse(filters.has("0xa"));
+  });
+
+  it("forgets every filter when cleared", function () {
+    filters.add("0x1");
+    filters.add("0x2");
+
+    filters.clear();
+
+    assert.isFalse(filters.has("0x1"));
+    assert.isFalse(filters.has("0x2"));
+  });
+});
@@ -0,0 +1,123 @@
+import { assert } from "chai";
+
+import { numberToRpcQuantity } from "../../../../../src/internal/core/jsonrpc/types/base-types";
+import {
+  FixedGasPriceOracle,
+  PercentileGasPriceOracle,
+} from "../../../../../src/internal/hardhat-network/provider/utils/gasPriceOracle";
+import { EIP1193Provider, RequestArguments } from "../../../../../src/types";
+
+function createProvider(feeHistory: {
+  baseFeePerGas: bigint[];
+  gasUsedRatio: number[];
+  reward: bigint[];
+}): EIP1193Provider & { requests: RequestArguments[] } {
+  const requests: RequestArguments[] = [];
+
+  return {
+    requests,
+    async request(args: RequestArguments): Promise<unknown> {
+      requests.push(args);
+
+      if (args.method !== "eth_feeHistory") {
+        throw new Error(`Unexpected method ${args.method}`);
+      }
+
+      return {
+        oldestBlock: "0x1",
+        baseFeePerGas: feeHistory.baseFeePerGas.map(numberToRpcQuantity),
+        gasUsedRatio: feeHistory.gasUsedRatio,
+        reward: feeHistory.reward.map((reward) => [
+          numberToRpcQuantity(reward),
+        ]),
+      };
+    },
+  };
+}
+
+describe("Gas price oracles", function () {
+  describe("FixedGasPriceOracle", function () {
+    it("returns the configured values", async function () {
+      const oracle = new FixedGasPriceOracle(7n, 3n);
+
+      assert.equal(await oracle.getGasPrice(), 7n);
+      assert.equal(await oracle.getMaxPriorityFeePerGas(), 3n);
+    });
+  });
+
+  describe("PercentileGasPriceOracle", function () {
+    it("requests the fee history of the configured blocks and percentile", async function () {
+      const provider = createProvider({
+        baseFeePerGas: [10n, 10n],
+        gasUsedRatio: [0.5],
+        reward: [2n],
+      });
+      const oracle = new PercentileGasPriceOracle(provider, 20, 60);
+
+      await oracle.getMaxPriorityFeePerGas();
+
+      assert.deepEqual(provider.requests, [
+        { method: "eth_feeHistory", params: ["0x14", "latest", [60]] },
+      ]);
+    });
+
+    it("averages the rewards of the non-empty blocks", async function () {
+      const oracle = new PercentileGasPriceOracle(
+        createProvider({
+          baseFeePerGas: [10n, 11n, 12n, 13n],
+          gasUsedRatio: [0.5, 0, 0.1],
+          reward: [4n, 0n, 8n],
+        }),
+        3,
+        50
+      );
+
+      assert.equal(await oracle.getMaxPriorityFeePerGas(), 6n);
+    });
+
+    it("adds the average reward to the next block's base fee", async function () {
+      const oracle = new PercentileGasPriceOracle(
+        createProvider({
+          baseFeePerGas: [10n, 11n, 12n],
+          gasUsedRatio: [0.5, 0.5],
+          reward: [4n, 8n],
+        }),
+        2,
+        50
+      );
+
+      assert.equal(await oracle.getGasPrice(), 18n);
+    });
+
+    it("falls back to the default priority fee if all the blocks are empty", async function () {
+      const oracle = new PercentileGasPriceOracle(
+        createProvider({
+          baseFeePerGas: [10n, 10n],
+          gasUsedRatio: [0],
+          reward: [0n],
+        }),
+        1,
+        50
+      );
+
+      assert.equal(await oracle.getMaxPriorityFeePerGas(), 1_000_000_000n);
+      assert.equal(await oracle.getGasPrice(), 1_000_000_010n);
+    });
+
+    it("falls back to the configured priority fee if all the blocks are empty", async function () {
+      const oracle = new PercentileGasPriceOracle(
+        createProvider({
+          baseFeePerGas: [10n, 10n],
+          gasUsedRatio: [0],
+          reward: [0n],
+        }),
+        1,
+        50,
+        5n
+      );
+
+      assert.equal(await oracle.getMaxPriorityFeePerGas(), 5n);
+      assert.equal(await oracle.getGasPrice(), 15n);
+    });
+  });
+});
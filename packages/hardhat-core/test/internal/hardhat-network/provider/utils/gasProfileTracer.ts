@@ -0,0 +1,97 @@
+import { assert } from "chai";
+
+import { computeGasProfile } from "../../../../../src/internal/hardhat-network/provider/utils/gasProfileTracer";
+
+const SENDER = "0x000000000000000000000000000000000000000a";
+const CONTRACT = "0x000000000000000000000000000000000000000b";
+const CALLEE = "0x000000000000000000000000000000000000000c";
+const LIBRARY = "0x000000000000000000000000000000000000000d";
+
+describe("Gas profile tracer", function () {
+  describe("computeGasProfile", function () {
+    it("attributes the gas of each frame to its contract and function", function () {
+      const profile = computeGasProfile({
+        type: "CALL",
+        from: SENDER,
+        to: CONTRACT,
+        value: "0x0",
+        gas: "0x186a0",
+        gasUsed: "0xc350",
+        input: `0xa9059cbb${"00".repeat(64)}`,
+        calls: [
+          {
+            type: "STATICCALL",
+            from: CONTRACT,
+            to: CALLEE,
+            gas: "0x2710",
+            gasUsed: "0x3e8",
+            input: "0x70a08231",
+            calls: [
+              {
+                type: "DELEGATECALL",
+                from: CALLEE,
+                to: LIBRARY,
+                gas: "0x1f4",
+                gasUsed: "0x64",
+                input: "0x",
+              },
+            ],
+          },
+        ],
+      });
+
+      assert.deepEqual(profile, {
+        type: "CALL",
+        address: CONTRACT,
+        selector: "0xa9059cbb",
+        gasUsed: "0xc350",
+        selfGasUsed: "0xbf68",
+        calls: [
+          {
+            type: "STATICCALL",
+            address: CALLEE,
+            selector: "0x70a08231",
+            gasUsed: "0x3e8",
+            selfGasUsed: "0x384",
+            calls: [
+              {
+                type: "DELEGATECALL",
+                address: LIBRARY,
+                gasUsed: "0x64",
+                selfGasUsed: "0x64",
+              },
+            ],
+          },
+        ],
+      });
+    });
+
+    it("doesn't use the init code of creations as a selector", function () {
+      const profile = computeGasProfile({
+        type: "CREATE",
+        from: SENDER,
+        value: "0x0",
+        gasUsed: "0x3e8",
+        input: "0x6080604052",
+      });
+
+      assert.deepEqual(profile, {
+        type: "CREATE",
+        gasUsed: "0x3e8",
+        selfGasUsed: "0x3e8",
+      });
+    });
+
+    it("doesn't return a negative gas used by the frame alone", function () {
+      const profile = computeGasProfile({
+        type: "CALL",
+        to: CONTRACT,
+        gasUsed: "0x64",
+        input: "0x",
+        calls: [{ type: "CALL", to: CALLEE, gasUsed: "0xc8", input: "0x" }],
+      });
+
+      assert.equal(profile.selfGasUsed, "0x0");
+    });
+  });
+});
@@ -0,0 +1,52 @@
+import { assert } from "chai";
+
+import { computeLogStatistics } from "../../../../../src/internal/hardhat-network/provider/utils/logStatistics";
+
+const TOKEN = "0x5fbdb2315678afecb367f032d93f642f64180aa3";
+const OTHER_CONTRACT = "0xe7f1725e7734ce288f8367e1bb143e90bb3f0512";
+
+const TRANSFER_TOPIC =
+  "0xddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef";
+const APPROVAL_TOPIC =
+  "0x8c5be1e5ebec7d5bd14f71427d1e84f3dd0314c0f7b2291e5b200ac8c7c3b925";
+
+describe("computeLogStatistics", function () {
+  it("returns empty statistics if there are no logs", function () {
+    assert.deepEqual(computeLogStatistics([]), {
+      totalLogs: 0,
+      addresses: {},
+    });
+  });
+
+  it("counts the logs per address and first topic", function () {
+    const statistics = computeLogStatistics([
+      { address: TOKEN, topics: [TRANSFER_TOPIC, "0x01"] },
+      {
+        address: TOKEN.toUpperCase().replace("0X", "0x"),
+        topics: [TRANSFER_TOPIC],
+      },
+      { address: TOKEN, topics: [APPROVAL_TOPIC] },
+      { address: OTHER_CONTRACT, topics: [TRANSFER_TOPIC] },
+      { address: OTHER_CONTRACT, topics: [] },
+    ]);
+
+    assert.deepEqual(statistics, {
+      totalLogs: 5,
+      addresses: {
+        [TOKEN]: {
+          totalLogs: 3,
+          topics: {
+            [TRANSFER_TOPIC]: 2,
+            [APPROVAL_TOPIC]: 1,
+          },
+        },
+        [OTHER_CONTRACT]: {
+          totalLogs: 2,
+          topics: {
+            [TRANSFER_TOPIC]: 1,
+          },
+        },
+      },
+    });
+  });
+});
@@ -0,0 +1,37 @@
+import { assert } from "chai";
+
+import { RpcStructLog } from "../../../../../src/internal/hardhat-network/provider/output";
+import { computeOpcodeStatistics } from "../../../../../src/internal/hardhat-network/provider/utils/opcodeStatistics";
+
+function step(op: string, gasCost: number): RpcStructLog {
+  return { depth: 1, gas: 100_000, gasCost, op, pc: 0 };
+}
+
+describe("Opcode statistics", function () {
+  describe("computeOpcodeStatistics", function () {
+    it("counts the steps and their gas per opcode", function () {
+      const statistics = computeOpcodeStatistics({
+        failed: false,
+        gas: 43_000,
+        returnValue: "",
+        structLogs: [
+          step("PUSH1", 3),
+          step("PUSH1", 3),
+          step("SSTORE", 22_100),
+          step("STOP", 0),
+        ],
+      });
+
+      assert.deepEqual(statistics, {
+        failed: false,
+        gas: 43_000,
+        totalSteps: 4,
+        opcodes: {
+          PUSH1: { count: 2, gas: 6 },
+          SSTORE: { count: 1, gas: 22_100 },
+          STOP: { count: 1, gas: 0 },
+        },
+      });
+    });
+  });
+});
@@ -0,0 +1,150 @@
+import { assert } from "chai";
+
+import { prefetchForkedState } from "../../../../../src/internal/hardhat-network/provider/utils/prefetchForkedState";
+import { EIP1193Provider, RequestArguments } from "../../../../../src/types";
+
+const TOKEN = "0x5fbdb2315678afecb367f032d93f642f64180aa3";
+const OTHER_CONTRACT = "0xe7f1725e7734ce288f8367e1bb143e90bb3f0512";
+const FORK_BLOCK = "0x10";
+
+function createProvider(failingMethod?: string): EIP1193Provider & {
+  requests: RequestArguments[];
+  maxPendingRequests: number;
+} {
+  const requests: RequestArguments[] = [];
+  let pendingRequests = 0;
+
+  const provider = {
+    requests,
+    maxPendingRequests: 0,
+    async request(args: RequestArguments): Promise<unknown> {
+      requests.push(args);
+      pendingRequests++;
+      provider.maxPendingRequests = Math.max(
+        provider.maxPendingRequests,
+        pendingRequests
+      );
+
+      await new Promise((resolve) => setImmediate(resolve));
+      pendingRequests--;
+
+      if (args.method === failingMethod) {
+        throw new Error("Remote node error");
+      }
+
+      return "0x1";
+    },
+  };
+
+  return provider;
+}
+
+describe("prefetchForkedState", function () {
+  it("fetches the code, balance and nonce of each address at the fork block", async function () {
+    const remoteProvider = createProvider();
+
+    await prefetchForkedState(remoteProvider, createProvider(), FORK_BLOCK, [
+      { address: TOKEN },
+      { address: OTHER_CONTRACT },
+    ]);
+
+    assert.deepEqual(remoteProvider.requests, [
+      { method: "eth_getCode", params: [TOKEN, FORK_BLOCK] },
+      { method: "eth_getBalance", params: [TOKEN, FORK_BLOCK] },
+      { method: "eth_getTransactionCount", params: [TOKEN, FORK_BLOCK] },
+      { method: "eth_getCode", params: [OTHER_CONTRACT, FORK_BLOCK] },
+      { method: "eth_getBalance", params: [OTHER_CONTRACT, FORK_BLOCK] },
+      {
+        method: "eth_getTransactionCount",
+        params: [OTHER_CONTRACT, FORK_BLOCK],
+      },
+    ]);
+  });
+
+  it("stores the fetched values in the local node", async function () {
+    const localProvider = createProvider();
+
+    await prefetchForkedState(createProvider(), localProvider, FORK_BLOCK, [
+      { address: TOKEN, storageRanges: [{ from: 0, to: 0 }] },
+    ]);
+
+    assert.deepEqual(localProvider.requests, [
+      { method: "hardhat_setCode", params: [TOKEN, "0x1"] },
+      { method: "hardhat_setBalance", params: [TOKEN, "0x1"] },
+      { method: "hardhat_setNonce", params: [TOKEN, "0x1"] },
+      {
+        method: "hardhat_setStorageAt",
+        params: [TOKEN, "0x0", `0x${"1".padStart(64, "0")}`],
+      },
+    ]);
+  });
+
+  it("fetches every slot of the storage ranges", async function () {
+    const remoteProvider = createProvider();
+
+    await prefetchForkedState(remoteProvider, createProvider(), FORK_BLOCK, [
+      {
+        address: TOKEN,
+        storageRanges: [
+          { from: 0, to: 2 },
+          { from: 10, to: 10 },
+        ],
+      },
+    ]);
+
+    const storageRequests = remoteProvider.requests.filter(
+      ({ method }) => method === "eth_getStorageAt"
+    );
+
+    assert.deepEqual(storageRequests, [
+      { method: "eth_getStorageAt", params: [TOKEN, "0x0", FORK_BLOCK] },
+      { method: "eth_getStorageAt", params: [TOKEN, "0x1", FORK_BLOCK] },
+      { method: "eth_getStorageAt", params: [TOKEN, "0x2", FORK_BLOCK] },
+      { method: "eth_getStorageAt", params: [TOKEN, "0xa", FORK_BLOCK] },
+    ]);
+  });
+
+  it("sends concurrent requests to the remote node, up to a limit", async function () {
+    const remoteProvider = createProvider();
+
+    await prefetchForkedState(remoteProvider, createProvider(), FORK_BLOCK, [
+      { address: TOKEN, storageRanges: [{ from: 0, to: 99 }] },
+    ]);
+
+    assert.lengthOf(remoteProvider.requests, 103);
+    assert.isAbove(remoteProvider.maxPendingRequests, 1);
+    assert.isAtMost(remoteProvider.maxPendingRequests, 16);
+  });
+
+  it("skips the values that can't be fetched", async function () {
+    const remoteProvider = createProvider("eth_getCode");
+    const localProvider = createProvider();
+
+    await prefetchForkedState(remoteProvider, localProvider, FORK_BLOCK, [
+      { address: TOKEN },
+      { address: OTHER_CONTRACT },
+    ]);
+
+    assert.lengthOf(remoteProvider.requests, 6);
+    assert.deepEqual(
+      localProvider.requests.map(({ method }) => method),
+      [
+        "hardhat_setBalance",
+        "hardhat_setNonce",
+        "hardhat_setBalance",
+        "hardhat_setNonce",
+      ]
+    );
+  });
+
+  it("continues if a value can't be stored", async function () {
+    const localProvider = createProvider("hardhat_setCode");
+
+    await prefetchForkedState(createProvider(), localProvider, FORK_BLOCK, [
+      { address: TOKEN },
+      { address: OTHER_CONTRACT },
+    ]);
+
+    assert.lengthOf(localProvider.requests, 6);
+  });
+});
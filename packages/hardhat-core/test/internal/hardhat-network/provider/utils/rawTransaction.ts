@@ -0,0 +1,132 @@
+import { assert } from "chai";
+import { Common } from "@nomicfoundation/ethereumjs-common";
+import {
+  BlobEIP4844Transaction,
+  FeeMarketEIP1559Transaction,
+  LegacyTransaction,
+  TypedTransaction,
+} from "@nomicfoundation/ethereumjs-tx";
+import { bytesToHex, toBytes } from "@nomicfoundation/ethereumjs-util";
+
+import {
+  bufferToRpcData,
+  numberToRpcQuantity,
+} from "../../../../../src/internal/core/jsonrpc/types/base-types";
+import { InternalError } from "../../../../../src/internal/core/providers/errors";
+import { encodeRpcTransaction } from "../../../../../src/internal/hardhat-network/provider/utils/rawTransaction";
+import { DEFAULT_ACCOUNTS } from "../../helpers/providers";
+
+const RECIPIENT = "0x0d2026b3ee6ec71fc6746adb6311f6d3ba1c000b";
+
+function toRpcTransaction(tx: TypedTransaction): any {
+  const json = tx.toJSON();
+
+  return {
+    ...json,
+    hash: bytesToHex(tx.hash()),
+    gas: json.gasLimit,
+    input: json.data,
+    to: json.to ?? null,
+  };
+}
+
+describe("encodeRpcTransaction", function () {
+  const common = Common.custom({ chainId: 31337, networkId: 31337 });
+  const privateKey = toBytes(DEFAULT_ACCOUNTS[0].privateKey);
+
+  it("encodes legacy transactions", function () {
+    const tx = LegacyTransaction.fromTxData(
+      {
+        nonce: 1n,
+        gasLimit: 21_000n,
+        gasPrice: 10n ** 9n,
+        to: RECIPIENT,
+        value: 1n,
+      },
+      { common }
+    ).sign(privateKey);
+
+    assert.equal(
+      encodeRpcTransaction({
+        ...toRpcTransaction(tx),
+        type: numberToRpcQuantity(0),
+      }),
+      bufferToRpcData(tx.serialize())
+    );
+  });
+
+  it("encodes EIP-1559 transactions", function () {
+    const tx = FeeMarketEIP1559Transaction.fromTxData(
+      {
+        chainId: 31337n,
+        nonce: 2n,
+        gasLimit: 50_000n,
+        maxFeePerGas: 2n * 10n ** 9n,
+        maxPriorityFeePerGas: 10n ** 9n,
+        to: RECIPIENT,
+        data: "0x1234",
+        accessList: [
+          {
+            address: RECIPIENT,
+            storageKeys: [`0x${"00".repeat(31)}01`],
+          },
+        ],
+      },
+      { common }
+    ).sign(privateKey);
+
+    assert.equal(
+      encodeRpcTransaction({
+        ...toRpcTransaction(tx),
+        type: numberToRpcQuantity(2),
+      }),
+      bufferToRpcData(tx.serialize())
+    );
+  });
+
+  it("encodes blob transactions", function () {
+    const cancunCommon = Common.custom(
+      { chainId: 31337, networkId: 31337 },
+      { hardfork: "cancun" }
+    );
+
+    const tx = BlobEIP4844Transaction.fromTxData(
+      {
+        chainId: 31337n,
+        nonce: 3n,
+        gasLimit: 50_000n,
+        maxFeePerGas: 2n * 10n ** 9n,
+        maxPriorityFeePerGas: 10n ** 9n,
+        maxFeePerBlobGas: 10n ** 9n,
+        to: RECIPIENT,
+        blobVersionedHashes: [`0x01${"ab".repeat(31)}`],
+      },
+      { common: cancunCommon }
+    ).sign(privateKey);
+
+    assert.equal(
+      encodeRpcTransaction({
+        ...toRpcTransaction(tx),
+        type: numberToRpcQuantity(3),
+      }),
+      bufferToRpcData(tx.serialize())
+    );
+  });
+
+  it("throws if the encoded transaction doesn't have the same hash", function () {
+    const tx = LegacyTransaction.fromTxData(
+      { gasLimit: 21_000n, gasPrice: 1n, to: RECIPIENT },
+      { common }
+    ).sign(privateKey);
+
+    assert.throws(
+      () =>
+        encodeRpcTransaction({
+          ...toRpcTransaction(tx),
+          type: numberToRpcQuantity(0),
+          value: numberToRpcQuantity(1),
+        }),
+      InternalError
+    );
+  });
+});
@@ -0,0 +1,109 @@
+import {
+  personalSign,
+  signTypedData,
+  SignTypedDataVersion,
+} from "@metamask/eth-sig-util";
+import { assert } from "chai";
+
+import { rpcDataToBuffer } from "../../../../../src/internal/core/jsonrpc/types/base-types";
+import { InvalidArgumentsError } from "../../../../../src/internal/core/providers/errors";
+import {
+  recoverMessageSigner,
+  verifyTypedDataSignature,
+} from "../../../../../src/internal/hardhat-network/provider/utils/signatures";
+import {
+  DEFAULT_ACCOUNTS,
+  DEFAULT_ACCOUNTS_ADDRESSES,
+} from "../../helpers/providers";
+
+const PRIVATE_KEY = rpcDataToBuffer(DEFAULT_ACCOUNTS[0].privateKey);
+const SIGNER = DEFAULT_ACCOUNTS_ADDRESSES[0];
+const OTHER_ADDRESS = DEFAULT_ACCOUNTS_ADDRESSES[1];
+
+const TYPED_DATA = {
+  types: {
+    EIP712Domain: [
+      { name: "name", type: "string" },
+      { name: "chainId", type: "uint256" },
+    ],
+    Mail: [
+      { name: "to", type: "address" },
+      { name: "contents", type: "string" },
+    ],
+  },
+  primaryType: "Mail" as const,
+  domain: { name: "Ether Mail", chainId: 31337 },
+  message: { to: OTHER_ADDRESS, contents: "Hello, Bob!" },
+};
+
+describe("Signatures", function () {
+  describe("recoverMessageSigner", function () {
+    it("returns the address that signed the message", function () {
+      const message = Buffer.from("Hello, world!");
+      const signature = personalSign({
+        privateKey: PRIVATE_KEY,
+        data: message,
+      });
+
+      assert.equal(
+        recoverMessageSigner(message, rpcDataToBuffer(signature)),
+        SIGNER
+      );
+    });
+
+    it("throws if the signature is malformed", function () {
+      assert.throws(
+        () => recoverMessageSigner(Buffer.from("Hello"), Buffer.alloc(1)),
+        InvalidArgumentsError
+      );
+    });
+  });
+
+  describe("verifyTypedDataSignature", function () {
+    const signature = rpcDataToBuffer(
+      signTypedData({
+        privateKey: PRIVATE_KEY,
+        data: TYPED_DATA,
+        version: SignTypedDataVersion.V4,
+      })
+    );
+
+    it("accepts a signature of the signer", function () {
+      assert.isTrue(
+        verifyTypedDataSignature(rpcDataToBuffer(SIGNER), TYPED_DATA, signature)
+      );
+    });
+
+    it("accepts the typed data as JSON", function () {
+      assert.isTrue(
+        verifyTypedDataSignature(
+          rpcDataToBuffer(SIGNER),
+          JSON.stringify(TYPED_DATA),
+          signature
+        )
+      );
+    });
+
+    it("rejects a signature of a different address", function () {
+      assert.isFalse(
+        verifyTypedDataSignature(
+          rpcDataToBuffer(OTHER_ADDRESS),
+          TYPED_DATA,
+          signature
+        )
+      );
+    });
+
+    it("throws if the typed data is invalid JSON", function () {
+      assert.throws(
+        () =>
+          verifyTypedDataSignature(
+            rpcDataToBuffer(SIGNER),
+            "{not json",
+            signature
+          ),
+        InvalidArgumentsError
+      );
+    });
+  });
+});
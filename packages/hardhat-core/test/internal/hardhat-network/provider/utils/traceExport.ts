@@ -0,0 +1,94 @@
+import { assert } from "chai";
+import fsExtra from "fs-extra";
+import os from "os";
+import path from "path";
+
+import {
+  extractTraceExportOption,
+  MAX_EXPORTED_TRACES,
+  writeTraceToFile,
+} from "../../../../../src/internal/hardhat-network/provider/utils/traceExport";
+
+const TX_HASH =
+  "0x09cfae3d6a1a2e3d4d58e3c3bba9e55aedd3a16df2b82f27ef04e4cd8a3a0f1a";
+
+describe("Trace export", function () {
+  describe("extractTraceExportOption", function () {
+    it("removes the option from the tracing config", function () {
+      assert.deepEqual(
+        extractTraceExportOption("debug_traceTransaction", [
+          TX_HASH,
+          { exportToFile: true, disableMemory: true },
+        ]),
+        [[TX_HASH, { disableMemory: true }], true]
+      );
+    });
+
+    it("returns false if the option isn't enabled", function () {
+      const params = [TX_HASH, { disableMemory: true }];
+
+      assert.deepEqual(
+        extractTraceExportOption("debug_traceTransaction", params),
+        [params, false]
+      );
+      assert.deepEqual(
+        extractTraceExportOption("debug_traceTransaction", [TX_HASH]),
+        [[TX_HASH], false]
+      );
+    });
+
+    it("ignores methods without a tracing config", function () {
+      const params = [{ exportToFile: true }];
+
+      assert.deepEqual(extractTraceExportOption("eth_call", params), [
+        params,
+        false,
+      ]);
+    });
+  });
+
+  describe("writeTraceToFile", function () {
+    it("writes a line per struct log followed by the rest of the trace", async function () {
+      const structLogs = [
+        { depth: 1, gas: 100, gasCost: 3, op: "PUSH1", pc: 0 },
+        { depth: 1, gas: 97, gasCost: 0, op: "STOP", pc: 2 },
+      ];
+
+      const file = await writeTraceToFile({
+        failed: false,
+        gas: 21003,
+        returnValue: "",
+        structLogs,
+      });
+
+      try {
+        assert.isTrue(file.startsWith(os.tmpdir()));
+
+        const lines = (await fsExtra.readFile(file, "utf8"))
+          .trimEnd()
+          .split("\n")
+          .map((line) => JSON.parse(line));
+
+        assert.deepEqual(lines, [
+          ...structLogs,
+          { failed: false, gas: 21003, returnValue: "" },
+        ]);
+      } finally {
+        await fsExtra.remove(file);
+      }
+    });
+
+    it("only keeps the most recent files", async function () {
+      const files: string[] = [];
+      for (let i = 0; i <= MAX_EXPORTED_TRACES; i++) {
+        files.push(await writeTraceToFile({ structLogs: [] }));
+      }
+
+      assert.isFalse(await fsExtra.pathExists(files[0]));
+      for (const file of files.slice(1)) {
+        assert.isTrue(await fsExtra.pathExists(file));
+        assert.equal(path.dirname(file), path.dirname(files[0]));
+      }
+    });
+  });
+});
@@ -0,0 +1,74 @@
+import { assert } from "chai";
+
+import { extractTracer } from "../../../../../src/internal/hardhat-network/provider/utils/tracingConfig";
+
+const TX_HASH =
+  "0x09cfae3d6a1a2e3d4d58e3c3bba9e55aedd3a16df2b82f27ef04e4cd8a3a0f1a";
+
+describe("extractTracer", function () {
+  it("ignores requests without the tracer", function () {
+    const params = [TX_HASH, { disableMemory: true }];
+
+    assert.deepEqual(
+      extractTracer("debug_traceTransaction", params, "callTracer", true),
+      [params, false]
+    );
+  });
+
+  it("ignores requests with other tracers", function () {
+    const params = [TX_HASH, { tracer: "4byteTracer" }];
+
+    assert.deepEqual(
+      extractTracer("debug_traceTransaction", params, "callTracer", true),
+      [params, false]
+    );
+  });
+
+  it("ignores methods without a tracing config", function () {
+    const params = [{ tracer: "callTracer" }];
+
+    assert.deepEqual(extractTracer("eth_call", params, "callTracer", true), [
+      params,
+      false,
+    ]);
+  });
+
+  it("replaces the tracer with a trace with memory and stack", function () {
+    assert.deepEqual(
+      extractTracer(
+        "debug_traceTransaction",
+        [TX_HASH, { tracer: "callTracer", timeout: "10s" }],
+        "callTracer",
+        true
+      ),
+      [
+        [
+          TX_HASH,
+          {
+            timeout: "10s",
+            disableMemory: false,
+            disableStack: false,
+            disableStorage: true,
+          },
+        ],
+        true,
+      ]
+    );
+  });
+
+  it("leaves the memory and stack out if the tracer doesn't read them", function () {
+    const [params, collect] = extractTracer(
+      "debug_traceCall",
+      [{ to: "0x01" }, "latest", { tracer: "opcodeStatsTracer" }],
+      "opcodeStatsTracer",
+      false
+    );
+
+    assert.isTrue(collect);
+    assert.deepEqual(params[2], {
+      disableMemory: true,
+      disableStack: true,
+      disableStorage: true,
+    });
+  });
+});
@@ -0,0 +1,74 @@
+import { assert } from "chai";
+
+import { TransactionReplacements } from "../../../../../src/internal/hardhat-network/provider/utils/transactionReplacements";
+
+const SENDER = "0xf39fd6e51aad88f6f4ce6ab8827279cfffb92266";
+const TX_HASH =
+  "0x09cfae3d6a1a2e3d4d58e3c3bba9e55aedd3a16df2b82f27ef04e4cd8a3a0f1a";
+const OTHER_TX_HASH =
+  "0x7b2b6d7b1e0dae2c1dac4ab1c5ba5d2ef0ed38e48dcb8fed0e06d9d32be2b7b1";
+const THIRD_TX_HASH =
+  "0x3f1a4cd5d26f3b87f7d3bb0c91e2c0e1f8e8c7f0b1a5d44c0e6cbe32b1a7f5d2";
+
+describe("TransactionReplacements", function () {
+  let replacements: TransactionReplacements;
+
+  beforeEach(function () {
+    replacements = new TransactionReplacements();
+  });
+
+  it("links a transaction to the one that replaced it", function () {
+    assert.isUndefined(replacements.addTransaction(SENDER, 0n, TX_HASH));
+    assert.equal(
+      replacements.addTransaction(
+        SENDER.toUpperCase().replace("0X", "0x"),
+        0n,
+        OTHER_TX_HASH
+      ),
+      TX_HASH
+    );
+
+    assert.equal(replacements.getReplacement(TX_HASH), OTHER_TX_HASH);
+    assert.isUndefined(replacements.getReplacement(OTHER_TX_HASH));
+  });
+
+  it("doesn't link transactions with different nonces", function () {
+    replacements.addTransaction(SENDER, 0n, TX_HASH);
+
+    assert.isUndefined(replacements.addTransaction(SENDER, 1n, OTHER_TX_HASH));
+    assert.isUndefined(replacements.getReplacement(TX_HASH));
+  });
+
+  it("doesn't consider sending the same transaction twice a replacement", function () {
+    replacements.addTransaction(SENDER, 0n, TX_HASH);
+
+    assert.isUndefined(replacements.addTransaction(SENDER, 0n, TX_HASH));
+    assert.isUndefined(replacements.getReplacement(TX_HASH));
+  });
+
+  it("forgets removed transactions", function () {
+    replacements.addTransaction(SENDER, 0n, TX_HASH);
+    replacements.removeTransaction(TX_HASH);
+
+    assert.isUndefined(replacements.addTransaction(SENDER, 0n, OTHER_TX_HASH));
+    assert.isUndefined(replacements.getReplacement(TX_HASH));
+  });
+
+  it("keeps the replacements when the sent transactions are cleared", function () {
+    replacements.addTransaction(SENDER, 0n, TX_HASH);
+    replacements.addTransaction(SENDER, 0n, OTHER_TX_HASH);
+    replacements.clearSentTransactions();
+
+    assert.isUndefined(replacements.addTransaction(SENDER, 0n, THIRD_TX_HASH));
+    assert.equal(replacements.getReplacement(TX_HASH), OTHER_TX_HASH);
+  });
+
+  it("forgets everything when cleared", function () {
+    replacements.addTransaction(SENDER, 0n, TX_HASH);
+    replacements.addTransaction(SENDER, 0n, OTHER_TX_HASH);
+    replacements.clear();
+
+    assert.isUndefined(replacements.getReplacement(TX_HASH));
+    assert.isUndefined(replacements.addTransaction(SENDER, 0n, THIRD_TX_HASH));
+  });
+});
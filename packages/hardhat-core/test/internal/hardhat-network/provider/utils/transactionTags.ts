@@ -0,0 +1,117 @@
+import { assert } from "chai";
+
+import { TransactionTags } from "../../../../../src/internal/hardhat-network/provider/utils/transactionTags";
+
+const TX_HASH =
+  "0x09cfae3d6a1a2e3d4d58e3c3bba9e55aedd3a16df2b82f27ef04e4cd8a3a0f1a";
+const OTHER_TX_HASH =
+  "0x7b2b6d7b1e0dae2c1dac4ab1c5ba5d2ef0ed38e48dcb8fed0e06d9d32be2b7b1";
+
+describe("TransactionTags", function () {
+  let tags: TransactionTags;
+
+  beforeEach(function () {
+    tags = new TransactionTags();
+  });
+
+  describe("extractTag", function () {
+    it("removes the tag from the transaction", function () {
+      const [params, tag] = tags.extractTag([{ from: "0x1", tag: "my test" }]);
+
+      assert.deepEqual(params, [{ from: "0x1" }]);
+      assert.strictEqual(tag, "my test");
+    });
+
+    it("leaves transactions without a tag untouched", function () {
+      const params = [{ from: "0x1" }];
+
+      assert.deepEqual(tags.extractTag(params), [params, undefined]);
+    });
+  });
+
+  describe("waitForPendingTags", function () {
+    it("waits until every transaction being sent was tagged", async function () {
+      const finishTagging = tags.startTagging();
+
+      let waited = false;
+      const waiting = tags.waitForPendingTags().then(() => {
+        waited = true;
+      });
+
+      await Promise.resolve();
+      assert.isFalse(waited);
+
+      tags.setTag(TX_HASH, "my test");
+      finishTagging();
+
+      await waiting;
+      assert.isTrue(waited);
+    });
+
+    it("doesn't wait if no transaction is being sent", async function () {
+      tags.startTagging()();
+
+      await tags.waitForPendingTags();
+    });
+  });
+
+  describe("addTagsToResult", function () {
+    beforeEach(function () {
+      tags.setTag(TX_HASH.toUpperCase().replace("0X", "0x"), { test: "a" });
+    });
+
+    it("adds the tag to receipts and transactions", function () {
+      assert.deepEqual(
+        tags.addTagsToResult("eth_getTransactionReceipt", [TX_HASH], {
+          transactionHash: TX_HASH,
+        }),
+        { transactionHash: TX_HASH, tag: { test: "a" } }
+      );
+
+      assert.deepEqual(
+        tags.addTagsToResult("eth_getTransactionByHash", [TX_HASH], {
+          hash: TX_HASH,
+        }),
+        { hash: TX_HASH, tag: { test: "a" } }
+      );
+    });
+
+    it("adds the tag to the full transactions of a block", function () {
+      const block = {
+        number: "0x1",
+        transactions: [{ hash: TX_HASH }, { hash: OTHER_TX_HASH }],
+      };
+
+      assert.deepEqual(
+        tags.addTagsToResult("eth_getBlockByNumber", ["0x1", true], block),
+        {
+          number: "0x1",
+          transactions: [
+            { hash: TX_HASH, tag: { test: "a" } },
+            { hash: OTHER_TX_HASH },
+          ],
+        }
+      );
+    });
+
+    it("adds the tag to traces", function () {
+      assert.deepEqual(
+        tags.addTagsToResult("debug_traceTransaction", [TX_HASH], {
+          structLogs: [],
+        }),
+        { structLogs: [], tag: { test: "a" } }
+      );
+    });
+
+    it("doesn't add removed tags", function () {
+      tags.setTag(TX_HASH, undefined);
+
+      assert.deepEqual(
+        tags.addTagsToResult("eth_getTransactionByHash", [TX_HASH], {
+          hash: TX_HASH,
+        }),
+        { hash: TX_HASH }
+      );
+    });
+  });
+});
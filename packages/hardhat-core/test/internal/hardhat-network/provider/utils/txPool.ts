@@ -0,0 +1,102 @@
+import { assert } from "chai";
+
+import { numberToRpcQuantity } from "../../../../../src/internal/core/jsonrpc/types/base-types";
+import {
+  getTxPoolContent,
+  getTxPoolStatus,
+  inspectTxPoolContent,
+} from "../../../../../src/internal/hardhat-network/provider/utils/txPool";
+
+const SENDER = "0xf39fd6e51aad88f6f4ce6ab8827279cfffb92266";
+const OTHER_SENDER = "0x70997970c51812dc3a010c7d01b50e0d17dc79c8";
+const RECIPIENT = "0x0d2026b3ee6ec71fc6746adb6311f6d3ba1c000b";
+
+function tx(from: string, nonce: number) {
+  return {
+    from,
+    to: RECIPIENT,
+    nonce: numberToRpcQuantity(nonce),
+    value: "0x1",
+    gas: numberToRpcQuantity(21_000),
+    gasPrice: numberToRpcQuantity(10n ** 9n),
+  };
+}
+
+describe("Transaction pool", function () {
+  const nonces: { [sender: string]: bigint } = {
+    [SENDER]: 3n,
+    [OTHER_SENDER]: 0n,
+  };
+
+  async function getNonce(sender: string) {
+    return nonces[sender];
+  }
+
+  describe("getTxPoolContent", function () {
+    it("returns empty groups for an empty mempool", async function () {
+      assert.deepEqual(await getTxPoolContent([], getNonce), {
+        pending: {},
+        queued: {},
+      });
+    });
+
+    it("splits the transactions that follow the sender's nonce from the rest", async function () {
+      const content = await getTxPoolContent(
+        [
+          tx(SENDER, 4),
+          tx(SENDER, 7),
+          tx(SENDER, 3),
+          tx(OTHER_SENDER, 1),
+          tx(SENDER, 8),
+        ],
+        getNonce
+      );
+
+      assert.deepEqual(content, {
+        pending: {
+          [SENDER]: { "3": tx(SENDER, 3), "4": tx(SENDER, 4) },
+        },
+        queued: {
+          [SENDER]: { "7": tx(SENDER, 7), "8": tx(SENDER, 8) },
+          [OTHER_SENDER]: { "1": tx(OTHER_SENDER, 1) },
+        },
+      });
+    });
+  });
+
+  it("inspectTxPoolContent summarizes each transaction", async function () {
+    const creation = { ...tx(OTHER_SENDER, 0), to: null };
+    const content = await getTxPoolContent(
+      [tx(SENDER, 3), creation, tx(OTHER_SENDER, 2)],
+      getNonce
+    );
+
+    assert.deepEqual(inspectTxPoolContent(content), {
+      pending: {
+        [SENDER]: {
+          "3": `${RECIPIENT}: 1 wei + 21000 gas × 1000000000 wei`,
+        },
+        [OTHER_SENDER]: {
+          "0": "contract creation: 1 wei + 21000 gas × 1000000000 wei",
+        },
+      },
+      queued: {
+        [OTHER_SENDER]: {
+          "2": `${RECIPIENT}: 1 wei + 21000 gas × 1000000000 wei`,
+        },
+      },
+    });
+  });
+
+  it("getTxPoolStatus counts the pending and queued transactions", async function () {
+    const content = await getTxPoolContent(
+      [tx(SENDER, 3), tx(SENDER, 4), tx(SENDER, 6), tx(OTHER_SENDER, 0)],
+      getNonce
+    );
+
+    assert.deepEqual(getTxPoolStatus(content), {
+      pending: "0x3",
+      queued: "0x1",
+    });
+  });
+});
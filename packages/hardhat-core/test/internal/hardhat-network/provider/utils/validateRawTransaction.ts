@@ -0,0 +1,281 @@
+import { assert } from "chai";
+import { Common } from "@nomicfoundation/ethereumjs-common";
+import { LegacyTransaction } from "@nomicfoundation/ethereumjs-tx";
+import { toBytes } from "@nomicfoundation/ethereumjs-util";
+
+import { numberToRpcQuantity } from "../../../../../src/internal/core/jsonrpc/types/base-types";
+import { ProviderError } from "../../../../../src/internal/core/providers/errors";
+import { validateRawTransaction } from "../../../../../src/internal/hardhat-network/provider/utils/validateRawTransaction";
+import { EIP1193Provider, RequestArguments } from "../../../../../src/types";
+import {
+  DEFAULT_ACCOUNTS,
+  DEFAULT_ACCOUNTS_ADDRESSES,
+} from "../../helpers/providers";
+
+const RECIPIENT = "0x0d2026b3ee6ec71fc6746adb6311f6d3ba1c000b";
+
+function createProvider({
+  nonce = 0n,
+  pendingTransactions = [],
+  balance = 10n ** 18n,
+  automine = true,
+  estimateGas = async () => numberToRpcQuantity(21_000),
+}: {
+  nonce?: bigint;
+  // Transactions of the sender in the mempool, from the given nonce on
+  pendingTransactions?: Array<{ hash: string; gasPrice: bigint }>;
+  balance?: bigint;
+  automine?: boolean;
+  estimateGas?: (blockTag: string) => Promise<string>;
+}): EIP1193Provider {
+  return {
+    async request({ method, params }: RequestArguments): Promise<unknown> {
+      const args = params as any[];
+
+      switch (method) {
+        case "eth_getTransactionByHash":
+          return null;
+        case "eth_getTransactionCount":
+          return numberToRpcQuantity(
+            args[1] === "pending"
+              ? nonce + BigInt(pendingTransactions.length)
+              : nonce
+          );
+        case "eth_pendingTransactions":
+          return pendingTransactions.map(({ hash, gasPrice }, i) => ({
+            hash,
+            from: DEFAULT_ACCOUNTS_ADDRESSES[0],
+            nonce: numberToRpcQuantity(nonce + BigInt(i)),
+            gasPrice: numberToRpcQuantity(gasPrice),
+          }));
+        case "eth_getBalance":
+          return numberToRpcQuantity(balance);
+        case "eth_getBlockByNumber":
+          return {
+            gasLimit: numberToRpcQuantity(30_000_000),
+            baseFeePerGas: numberToRpcQuantity(10n ** 8n),
+          };
+        case "hardhat_getAutomine":
+          return automine;
+        case "eth_estimateGas":
+          return estimateGas(args[1]);
+      }
+
+      throw new Error(`Unexpected method ${method}`);
+    },
+  };
+}
+
+describe("validateRawTransaction", function () {
+  const common = new Common({ chain: "mainnet", hardfork: "shanghai" });
+
+  function signTransaction(
+    txData: Partial<{
+      nonce: bigint;
+      gasLimit: bigint;
+      gasPrice: bigint;
+      value: bigint;
+    }> = {},
+    txCommon = common
+  ): Buffer {
+    const tx = LegacyTransaction.fromTxData(
+      {
+        nonce: 0n,
+        gasLimit: 21_000n,
+        gasPrice: 10n ** 9n,
+        to: RECIPIENT,
+        value: 1n,
+        ...txData,
+      },
+      { common: txCommon }
+    );
+
+    return Buffer.from(
+      tx.sign(toBytes(DEFAULT_ACCOUNTS[0].privateKey)).serialize()
+    );
+  }
+
+  it("accepts a valid transaction and estimates its gas", async function () {
+    const verdict = await validateRawTransaction(
+      createProvider({}),
+      common,
+      0n,
+      signTransaction()
+    );
+
+    assert.isTrue(verdict.accepted);
+    assert.deepEqual(verdict.errors, []);
+    assert.equal(verdict.from, DEFAULT_ACCOUNTS_ADDRESSES[0]);
+    assert.equal(verdict.gasEstimate, numberToRpcQuantity(21_000));
+    assert.isUndefined(verdict.revert);
+  });
+
+  it("rejects transactions that can't be decoded", async function () {
+    const verdict = await validateRawTransaction(
+      createProvider({}),
+      common,
+      0n,
+      Buffer.from("1234", "hex")
+    );
+
+    assert.isFalse(verdict.accepted);
+    assert.lengthOf(verdict.errors, 1);
+    assert.include(verdict.errors[0], "Invalid transaction");
+  });
+
+  it("rejects transactions with a nonce that was already used", async function () {
+    const verdict = await validateRawTransaction(
+      createProvider({ nonce: 1n }),
+      common,
+      0n,
+      signTransaction()
+    );
+
+    assert.isFalse(verdict.accepted);
+    assert.include(verdict.errors[0], "Nonce too low");
+  });
+
+  it("rejects transactions with a future nonce when automining", async function () {
+    const verdict = await validateRawTransaction(
+      createProvider({}),
+      common,
+      0n,
+      signTransaction({ nonce: 1n })
+    );
+
+    assert.isFalse(verdict.accepted);
+    assert.include(verdict.errors[0], "Nonce too high");
+  });
+
+  it("reports transactions with a future nonce as queued without automine", async function () {
+    const verdict = await validateRawTransaction(
+      createProvider({ automine: false }),
+      common,
+      0n,
+      signTransaction({ nonce: 1n })
+    );
+
+    assert.isTrue(verdict.accepted);
+    assert.isTrue(verdict.queued);
+    assert.isUndefined(verdict.gasEstimate);
+  });
+
+  it("rejects transactions with less than the intrinsic gas", async function () {
+    const verdict = await validateRawTransaction(
+      createProvider({}),
+      common,
+      0n,
+      signTransaction({ gasLimit: 20_000n })
+    );
+
+    assert.isFalse(verdict.accepted);
+    assert.include(verdict.errors[0], "requires at least 21000 gas");
+  });
+
+  it("rejects transactions whose sender can't pay for them", async function () {
+    const verdict = await validateRawTransaction(
+      createProvider({ balance: 1000n }),
+      common,
+      0n,
+      signTransaction()
+    );
+
+    assert.isFalse(verdict.accepted);
+    assert.include(verdict.errors[0], "doesn't have enough funds");
+    assert.isUndefined(verdict.gasEstimate);
+  });
+
+  it("accepts reverting transactions and reports the revert", async function () {
+    const verdict = await validateRawTransaction(
+      createProvider({
+        estimateGas: async () => {
+          throw new ProviderError("execution reverted", -32003);
+        },
+      }),
+      common,
+      0n,
+      signTransaction()
+    );
+
+    assert.isTrue(verdict.accepted);
+    assert.equal(verdict.revert?.message, "execution reverted");
+  });
+
+  describe("replacements", function () {
+    const PENDING_TX_HASH = `0x${"11".repeat(32)}`;
+
+    it("accepts replacements that pay at least 10% more", async function () {
+      let estimationBlockTag: string | undefined;
+      const verdict = await validateRawTransaction(
+        createProvider({
+          pendingTransactions: [{ hash: PENDING_TX_HASH, gasPrice: 100n }],
+          automine: false,
+          estimateGas: async (blockTag) => {
+            estimationBlockTag = blockTag;
+            return numberToRpcQuantity(21_000);
+          },
+        }),
+        common,
+        0n,
+        signTransaction({ gasPrice: 110n })
+      );
+
+      assert.isTrue(verdict.accepted);
+      assert.equal(verdict.replaces, PENDING_TX_HASH);
+      assert.equal(verdict.gasEstimate, numberToRpcQuantity(21_000));
+      // The replaced transaction isn't part of the state it would run on
+      assert.equal(estimationBlockTag, "latest");
+    });
+
+    it("rejects underpriced replacements", async function () {
+      const verdict = await validateRawTransaction(
+        createProvider({
+          pendingTransactions: [{ hash: PENDING_TX_HASH, gasPrice: 100n }],
+          automine: false,
+        }),
+        common,
+        0n,
+        signTransaction({ gasPrice: 109n })
+      );
+
+      assert.isFalse(verdict.accepted);
+      assert.include(
+        verdict.errors[0],
+        "Replacement transaction underpriced. A gasPrice/maxFeePerGas of at least 110"
+      );
+    });
+
+    it("doesn't estimate replacements of later transactions", async function () {
+      const verdict = await validateRawTransaction(
+        createProvider({
+          pendingTransactions: [
+            { hash: `0x${"22".repeat(32)}`, gasPrice: 100n },
+            { hash: PENDING_TX_HASH, gasPrice: 100n },
+          ],
+          automine: false,
+        }),
+        common,
+        0n,
+        signTransaction({ nonce: 1n, gasPrice: 200n })
+      );
+
+      assert.isTrue(verdict.accepted);
+      assert.equal(verdict.replaces, PENDING_TX_HASH);
+      assert.isUndefined(verdict.gasEstimate);
+    });
+  });
+
+  it("rejects transactions below the minGasPrice before London", async function () {
+    const berlinCommon = new Common({ chain: "mainnet", hardfork: "berlin" });
+
+    const verdict = await validateRawTransaction(
+      createProvider({}),
+      berlinCommon,
+      2n * 10n ** 9n,
+      signTransaction({}, berlinCommon)
+    );
+
+    assert.isFalse(verdict.accepted);
+    assert.include(verdict.errors[0], "below the minimum of 2000000000");
+  });
+});